@@ -1,15 +1,27 @@
 use multiversx_sc::api::ED25519_SIGNATURE_BYTE_LEN;
 
-use crate::governance::proposal::Proposal;
+use crate::governance::proposal::{Proposal, QuorumMode, VoteMode, VoteReceipt};
 
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
 pub const VOTING_PERIOD_MINUTES_DEFAULT: usize = 4320; // 3 days
 pub const VOTING_PERIOD_MINUTES_MAX: usize = 20_160; // 14 days
+pub const EXECUTION_DELAY_MINUTES_MAX: usize = 20_160; // 14 days
+pub const VOTING_DELAY_MINUTES_MAX: usize = 20_160; // 14 days
+pub const PROPOSAL_COOLDOWN_MINUTES_MAX: usize = 20_160; // 14 days
+pub const GRACE_PERIOD_MINUTES_MAX: usize = 43_200; // 30 days
+pub const ESCROW_LOCK_MINUTES_MAX: usize = 2_102_400; // 4 years
 pub const MIN_PROPOSAL_VOTE_WEIGHT_DEFAULT: u64 = 1;
 pub const QUORUM_DEFAULT: u64 = 1;
+pub const PASS_THRESHOLD_PERCENT_DEFAULT: u64 = 50;
+pub const PASS_THRESHOLD_PERCENT_MAX: u64 = 100;
+pub const QUORUM_PERCENT_MAX: u64 = 100;
+pub const DYNAMIC_QUORUM_SAMPLE_SIZE_MAX: usize = 50;
+pub const VOTE_TOKEN_MULTIPLIER_BASE: u64 = 100; // 100 = 1x
 
 pub const POLL_MAX_OPTIONS: u8 = 20;
+pub const POLL_MAX_RANKED_BALLOTS: usize = 100;
 
 pub const GAS_LIMIT_SET_TOKEN_ROLES: u64 = 60_000_000;
 
@@ -17,6 +29,39 @@ pub const TOKEN_MAX_DECIMALS: u8 = 18;
 
 pub type UserId = usize;
 
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct VoteToken {
+    pub multiplier: u64,
+    pub decimals: u8,
+}
+
+/// Describes where the unlock epoch is encoded in the attributes of a locked MetaESDT vote token.
+/// The unlock epoch is read as a big-endian integer of `unlock_epoch_length` bytes starting at `unlock_epoch_offset`.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct LockedVoteTokenLayout {
+    pub unlock_epoch_offset: usize,
+    pub unlock_epoch_length: usize,
+    pub max_lock_epochs: u64,
+}
+
+/// Bounds and sample size of the dynamic quorum, which follows the average turnout of recently finished proposals.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct DynamicQuorum<M: ManagedTypeApi> {
+    pub min: BigUint<M>,
+    pub max: BigUint<M>,
+    pub sample_size: usize,
+}
+
+/// Voting period, quorum and pass threshold of emergency proposals.
+/// The quorum and pass threshold never fall below the ones of regular proposals.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct EmergencyConfig<M: ManagedTypeApi> {
+    pub voting_period_minutes: usize,
+    pub quorum: BigUint<M>,
+    pub pass_threshold: u64,
+    pub execution_delay_minutes: usize,
+}
+
 #[multiversx_sc::module]
 pub trait ConfigModule {
     fn require_caller_self(&self) {
@@ -36,17 +81,18 @@ pub trait ConfigModule {
     }
 
     fn require_payments_with_gov_token(&self, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) {
-        let gov_token_id = self.gov_token_id().get();
-
         for payment in payments.into_iter() {
-            require!(payment.token_identifier == gov_token_id, "invalid payment token");
+            require!(self.is_vote_token(&payment.token_identifier), "invalid payment token");
         }
     }
 
     fn require_gov_tokens_available(&self, amount: &BigUint, nonce: u64) {
-        let gov_token_id = self.gov_token_id().get();
-        let protected = self.guarded_vote_tokens(&gov_token_id, nonce).get();
-        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(gov_token_id), nonce);
+        self.require_vote_tokens_available(&self.gov_token_id().get(), amount, nonce);
+    }
+
+    fn require_vote_tokens_available(&self, token_id: &TokenIdentifier, amount: &BigUint, nonce: u64) {
+        let protected = self.guarded_vote_tokens(token_id, nonce).get();
+        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(token_id.clone()), nonce);
         let available = balance - protected;
 
         require!(amount <= &available, "not enough governance tokens available");
@@ -65,16 +111,167 @@ pub trait ConfigModule {
     }
 
     fn require_vote_tokens_allowed(&self, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) {
+        self.require_vote_token_nonces_weighted(payments);
+
         if self.restricted_vote_nonces().is_empty() {
             return;
         }
 
+        // nonce restrictions apply to the governance token only, other vote tokens have unrelated nonces.
         for payment in payments.into_iter() {
+            if !self.is_gov_token(&payment.token_identifier) {
+                continue;
+            }
+
             let allowed = self.restricted_vote_nonces().contains(&payment.token_nonce);
             require!(allowed, "vote token nonce is restricted");
         }
     }
 
+    fn require_vote_token_nonces_weighted(&self, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) {
+        for payment in payments.into_iter() {
+            if let Some(weight) = self.get_vote_nonce_weight(&payment.token_identifier, payment.token_nonce) {
+                require!(weight > 0, "vote token nonce is excluded");
+            }
+        }
+    }
+
+    fn get_vote_weight_from_payments(&self, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) -> BigUint {
+        payments
+            .into_iter()
+            .fold(BigUint::zero(), |carry, payment| carry + self.get_vote_weight_from_payment(&payment))
+    }
+
+    /// Returns the vote weight of a payment in units of the governance token.
+    /// Amounts of registered vote tokens are normalized to the decimals of the governance token and then scaled by their multiplier.
+    fn get_vote_weight_from_payment(&self, payment: &EsdtTokenPayment<Self::Api>) -> BigUint {
+        let amount = match self.get_vote_nonce_weight(&payment.token_identifier, payment.token_nonce) {
+            Some(nonce_weight) => &payment.amount * &nonce_weight,
+            None => payment.amount.clone(),
+        };
+
+        let amount = match self.locked_vote_token_layout(&payment.token_identifier).is_empty() {
+            true => amount,
+            false => self.get_locked_vote_token_weight(payment, amount),
+        };
+
+        let vote_token = match self.vote_tokens().get(&payment.token_identifier) {
+            Some(vote_token) => vote_token,
+            None => return amount,
+        };
+
+        let gov_token_decimals = self
+            .vote_tokens()
+            .get(&self.gov_token_id().get())
+            .map(|gov_token| gov_token.decimals)
+            .unwrap_or(vote_token.decimals);
+
+        let weight = amount * vote_token.multiplier;
+        let normalized_weight = if gov_token_decimals >= vote_token.decimals {
+            weight * BigUint::from(10u64).pow((gov_token_decimals - vote_token.decimals) as u32)
+        } else {
+            weight / BigUint::from(10u64).pow((vote_token.decimals - gov_token_decimals) as u32)
+        };
+
+        normalized_weight / VOTE_TOKEN_MULTIPLIER_BASE
+    }
+
+    /// Returns the per-unit vote weight of a governance token nonce, or None if no nonce weights are configured.
+    /// Nonces missing from the weight table fall back to the default weight, where a default of 0 excludes them from voting.
+    fn get_vote_nonce_weight(&self, token_id: &TokenIdentifier, nonce: u64) -> Option<BigUint> {
+        if self.vote_nonce_weights().is_empty() || !self.is_gov_token(token_id) {
+            return None;
+        }
+
+        match self.vote_nonce_weights().get(&nonce) {
+            Some(weight) => Some(weight),
+            None => Some(self.vote_nonce_weight_default().get()),
+        }
+    }
+
+    fn try_set_vote_nonce_weights(&self, weights: MultiValueEncoded<MultiValue2<u64, BigUint>>) {
+        for entry in weights.into_iter() {
+            let (nonce, weight) = entry.into_tuple();
+            require!(nonce != 0, "invalid nonce");
+
+            if weight == 0 {
+                self.vote_nonce_weights().remove(&nonce);
+            } else {
+                self.vote_nonce_weights().insert(nonce, weight);
+            }
+        }
+    }
+
+    /// Scales the amount of a locked MetaESDT by its remaining lock time relative to the configured max lock duration.
+    /// Expired positions and tokens whose attributes do not match the layout have no weight.
+    fn get_locked_vote_token_weight(&self, payment: &EsdtTokenPayment<Self::Api>, amount: BigUint) -> BigUint {
+        let layout = self.locked_vote_token_layout(&payment.token_identifier).get();
+        let token_data = self
+            .blockchain()
+            .get_esdt_token_data(&self.blockchain().get_sc_address(), &payment.token_identifier, payment.token_nonce);
+
+        let unlock_epoch = match token_data
+            .attributes
+            .copy_slice(layout.unlock_epoch_offset, layout.unlock_epoch_length)
+            .and_then(|unlock_epoch| unlock_epoch.parse_as_u64())
+        {
+            Some(unlock_epoch) => unlock_epoch,
+            None => return BigUint::zero(),
+        };
+
+        let remaining_epochs = unlock_epoch.saturating_sub(self.blockchain().get_block_epoch()).min(layout.max_lock_epochs);
+
+        amount * remaining_epochs / layout.max_lock_epochs
+    }
+
+    fn try_set_locked_vote_token_layout(&self, token_id: &TokenIdentifier, unlock_epoch_offset: usize, unlock_epoch_length: usize, max_lock_epochs: u64) {
+        require!(self.is_vote_token(token_id), "token is not a vote token");
+        require!(unlock_epoch_length > 0 && unlock_epoch_length <= 8, "invalid unlock epoch length");
+        require!(max_lock_epochs > 0, "invalid max lock epochs");
+
+        self.locked_vote_token_layout(token_id).set(LockedVoteTokenLayout {
+            unlock_epoch_offset,
+            unlock_epoch_length,
+            max_lock_epochs,
+        });
+    }
+
+    fn is_vote_token(&self, token_id: &TokenIdentifier) -> bool {
+        token_id == &self.gov_token_id().get() || self.vote_tokens().contains_key(token_id)
+    }
+
+    fn try_set_vote_token(&self, token_id: TokenIdentifier, multiplier: u64, decimals: u8) {
+        require!(token_id.is_valid_esdt_identifier(), "invalid token id");
+        require!(multiplier > 0, "invalid multiplier");
+        require!(decimals <= TOKEN_MAX_DECIMALS, "invalid decimals");
+
+        if token_id != self.gov_token_id().get() {
+            require!(self.vote_tokens().contains_key(&self.gov_token_id().get()), "gov token must be registered first");
+        }
+
+        self.vote_tokens().insert(token_id, VoteToken { multiplier, decimals });
+    }
+
+    fn try_remove_vote_token(&self, token_id: &TokenIdentifier) {
+        require!(self.vote_tokens().contains_key(token_id), "vote token does not exist");
+
+        if token_id == &self.gov_token_id().get() {
+            require!(self.vote_tokens().len() == 1, "other vote tokens depend on the gov token");
+        }
+
+        self.vote_tokens().remove(token_id);
+    }
+
+    /// Returns the nonces of a collection's NFTs that have voted on a proposal and were returned to the voter.
+    /// The governance token keeps its original storage, while other vote tokens are tracked per collection.
+    fn get_proposal_nft_votes(&self, proposal_id: u64, token_id: &TokenIdentifier) -> UnorderedSetMapper<u64> {
+        if token_id == &self.gov_token_id().get() {
+            return self.proposal_nft_votes(proposal_id);
+        }
+
+        self.proposal_token_nft_votes(proposal_id, token_id)
+    }
+
     fn try_change_governance_token(&self, token_id: &TokenIdentifier) {
         require!(token_id.is_valid_esdt_identifier(), "invalid token id");
         self.gov_token_id().set(token_id);
@@ -85,6 +282,99 @@ pub trait ConfigModule {
         self.quorum().set(&quorum);
     }
 
+    fn try_change_quorum_percent(&self, percent: u64) {
+        require!(percent <= QUORUM_PERCENT_MAX, "invalid quorum percent");
+        require!(percent == 0 || self.gov_token_supply().get() > 0, "gov token supply not tracked");
+        self.quorum_percent().set(percent);
+    }
+
+    fn try_change_dynamic_quorum(&self, min: BigUint, max: BigUint, sample_size: usize) {
+        require!(min > 0 && max >= min, "invalid dynamic quorum bounds");
+        require!(sample_size > 0 && sample_size <= DYNAMIC_QUORUM_SAMPLE_SIZE_MAX, "invalid dynamic quorum sample size");
+
+        self.dynamic_quorum().set(DynamicQuorum { min, max, sample_size });
+    }
+
+    fn try_change_emergency_config(&self, voting_period_minutes: usize, quorum: BigUint, pass_threshold: u64, execution_delay_minutes: usize) {
+        require!(voting_period_minutes != 0, "voting period can not be zero");
+        require!(voting_period_minutes <= VOTING_PERIOD_MINUTES_MAX, "max voting period exceeded");
+        require!(quorum != 0, "invalid quorum");
+        self.require_valid_pass_threshold(pass_threshold);
+        self.require_valid_execution_delay(execution_delay_minutes);
+
+        self.emergency_config().set(EmergencyConfig {
+            voting_period_minutes,
+            quorum,
+            pass_threshold,
+            execution_delay_minutes,
+        });
+    }
+
+    /// Returns the quorum used to evaluate proposals.
+    /// If a quorum percent is configured, it is calculated from the circulating governance token supply, otherwise the absolute quorum applies.
+    #[view(getCurrentQuorum)]
+    fn get_quorum(&self) -> BigUint {
+        let percent = self.quorum_percent().get();
+
+        if percent == 0 || self.gov_token_id().is_empty() {
+            return self.quorum().get();
+        }
+
+        let quorum = self.get_circulating_supply() * percent / QUORUM_PERCENT_MAX;
+
+        if quorum == 0 {
+            return BigUint::from(1u64);
+        }
+
+        quorum
+    }
+
+    /// Returns the tracked governance token supply minus the tokens held by the contract.
+    /// Both the treasury and the tokens guarded for votes, delegations or deposits are excluded.
+    #[view(getCirculatingSupply)]
+    fn get_circulating_supply(&self) -> BigUint {
+        let supply = self.gov_token_supply().get();
+
+        if self.gov_token_id().is_empty() {
+            return supply;
+        }
+
+        let gov_token_id = self.gov_token_id().get();
+        let held = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(gov_token_id), 0);
+
+        if held >= supply {
+            return BigUint::zero();
+        }
+
+        supply - held
+    }
+
+    fn is_gov_token(&self, token_id: &TokenIdentifier) -> bool {
+        !self.gov_token_id().is_empty() && &self.gov_token_id().get() == token_id
+    }
+
+    fn try_change_pass_threshold(&self, threshold: u64) {
+        self.require_valid_pass_threshold(threshold);
+        self.pass_threshold().set(threshold);
+    }
+
+    fn require_valid_pass_threshold(&self, threshold: u64) {
+        require!(
+            (PASS_THRESHOLD_PERCENT_DEFAULT..=PASS_THRESHOLD_PERCENT_MAX).contains(&threshold),
+            "invalid pass threshold"
+        );
+    }
+
+    /// Returns the percentage of FOR votes (relative to FOR and AGAINST votes) required for a proposal to pass.
+    #[view(getPassThreshold)]
+    fn get_pass_threshold(&self) -> u64 {
+        if self.pass_threshold().is_empty() {
+            return PASS_THRESHOLD_PERCENT_DEFAULT;
+        }
+
+        self.pass_threshold().get()
+    }
+
     fn try_change_min_vote_weight(&self, vote_weight: BigUint) {
         require!(vote_weight != 0, "min vote weight can not be zero");
         self.min_vote_weight().set(&vote_weight);
@@ -101,6 +391,40 @@ pub trait ConfigModule {
         self.voting_period_in_minutes().set(&voting_period);
     }
 
+    fn try_change_voting_delay_in_minutes(&self, voting_delay: usize) {
+        require!(voting_delay <= VOTING_DELAY_MINUTES_MAX, "max voting delay exceeded");
+        self.voting_delay_in_minutes().set(voting_delay);
+    }
+
+    fn try_change_proposal_cooldown_in_minutes(&self, cooldown: usize) {
+        require!(cooldown <= PROPOSAL_COOLDOWN_MINUTES_MAX, "max proposal cooldown exceeded");
+        self.proposal_cooldown_in_minutes().set(cooldown);
+    }
+
+    fn try_change_late_vote_extension(&self, window_minutes: usize, extension_minutes: usize, max_extensions: usize) {
+        require!(window_minutes <= VOTING_PERIOD_MINUTES_MAX, "max late vote window exceeded");
+        require!(extension_minutes <= VOTING_PERIOD_MINUTES_MAX, "max late vote extension exceeded");
+        require!(window_minutes == 0 || (extension_minutes > 0 && max_extensions > 0), "invalid late vote extension");
+
+        self.late_vote_window_in_minutes().set(window_minutes);
+        self.late_vote_extension_in_minutes().set(extension_minutes);
+        self.max_late_vote_extensions().set(max_extensions);
+    }
+
+    fn try_change_execution_delay_in_minutes(&self, execution_delay: usize) {
+        self.require_valid_execution_delay(execution_delay);
+        self.execution_delay_in_minutes().set(execution_delay);
+    }
+
+    fn require_valid_execution_delay(&self, execution_delay: usize) {
+        require!(execution_delay <= EXECUTION_DELAY_MINUTES_MAX, "max execution delay exceeded");
+    }
+
+    fn try_change_grace_period_in_minutes(&self, grace_period: usize) {
+        require!(grace_period <= GRACE_PERIOD_MINUTES_MAX, "max grace period exceeded");
+        self.grace_period_in_minutes().set(grace_period);
+    }
+
     #[storage_mapper("users")]
     fn users(&self) -> UserMapper;
 
@@ -116,6 +440,13 @@ pub trait ConfigModule {
     #[storage_mapper("guarded_vote_tokens")]
     fn guarded_vote_tokens(&self, token_id: &TokenIdentifier, nonce: u64) -> SingleValueMapper<BigUint>;
 
+    #[storage_mapper("vote_tokens")]
+    fn vote_tokens(&self) -> MapMapper<TokenIdentifier, VoteToken>;
+
+    #[view(getLockedVoteTokenLayout)]
+    #[storage_mapper("locked_vote_token_layout")]
+    fn locked_vote_token_layout(&self, token_id: &TokenIdentifier) -> SingleValueMapper<LockedVoteTokenLayout>;
+
     #[view(isLockingVoteTokens)]
     #[storage_mapper("lock_vote_tokens")]
     fn lock_vote_tokens(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
@@ -127,6 +458,19 @@ pub trait ConfigModule {
     #[storage_mapper("proposals_id_counter")]
     fn next_proposal_id(&self) -> SingleValueMapper<u64>;
 
+    #[storage_mapper("vetoed_proposals")]
+    fn vetoed_proposals(&self) -> UnorderedSetMapper<u64>;
+
+    #[view(getProposalsByProposer)]
+    #[storage_mapper("proposer_proposals")]
+    fn proposer_proposals(&self, proposer: &ManagedAddress) -> VecMapper<u64>;
+
+    #[storage_mapper("proposer_active_proposals")]
+    fn proposer_active_proposals(&self, proposer: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("proposer_last_proposed_at")]
+    fn proposer_last_proposed_at(&self, proposer: &ManagedAddress) -> SingleValueMapper<u64>;
+
     #[storage_mapper("proposal_signers")]
     fn proposal_signers(&self, proposal_id: u64, role_name: &ManagedBuffer) -> UnorderedSetMapper<usize>;
 
@@ -134,6 +478,9 @@ pub trait ConfigModule {
     #[storage_mapper("proposal_nft_votes")]
     fn proposal_nft_votes(&self, proposal_id: u64) -> UnorderedSetMapper<u64>;
 
+    #[storage_mapper("proposal_token_nft_votes")]
+    fn proposal_token_nft_votes(&self, proposal_id: u64, token_id: &TokenIdentifier) -> UnorderedSetMapper<u64>;
+
     #[storage_mapper("proposal_poll")]
     fn proposal_poll(&self, proposal_id: u64, option_id: u8) -> SingleValueMapper<BigUint>;
 
@@ -149,6 +496,10 @@ pub trait ConfigModule {
     #[storage_mapper("withdrawable_votes")]
     fn withdrawable_votes(&self, proposal_id: u64, voter: &ManagedAddress) -> VecMapper<EsdtTokenPayment>;
 
+    #[view(getVoteReceipt)]
+    #[storage_mapper("vote_receipts")]
+    fn vote_receipts(&self, proposal_id: u64, voter: &ManagedAddress) -> VecMapper<VoteReceipt<Self::Api>>;
+
     // keep for backwards compatibility
     #[view(getProposalAddressVotes)]
     #[storage_mapper("votes")]
@@ -161,6 +512,51 @@ pub trait ConfigModule {
     #[storage_mapper("quorum")]
     fn quorum(&self) -> SingleValueMapper<BigUint>;
 
+    #[view(getQuorumPercent)]
+    #[storage_mapper("quorum_percent")]
+    fn quorum_percent(&self) -> SingleValueMapper<u64>;
+
+    #[view(getGovTokenSupply)]
+    #[storage_mapper("gov_token_supply")]
+    fn gov_token_supply(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getDynamicQuorum)]
+    #[storage_mapper("dynamic_quorum")]
+    fn dynamic_quorum(&self) -> SingleValueMapper<DynamicQuorum<Self::Api>>;
+
+    #[storage_mapper("dynamic_quorum:pending")]
+    fn dynamic_quorum_pending_proposals(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("dynamic_quorum:turnouts")]
+    fn proposal_turnouts(&self) -> QueueMapper<BigUint>;
+
+    #[view(getEmergencyConfig)]
+    #[storage_mapper("emergency_config")]
+    fn emergency_config(&self) -> SingleValueMapper<EmergencyConfig<Self::Api>>;
+
+    #[view(getEmergencyPermissions)]
+    #[storage_mapper("emergency_permissions")]
+    fn emergency_permissions(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(getQuorumMode)]
+    #[storage_mapper("quorum_mode")]
+    fn quorum_mode(&self) -> SingleValueMapper<QuorumMode>;
+
+    #[view(isQuadraticVoting)]
+    #[storage_mapper("quadratic_voting")]
+    fn quadratic_voting(&self) -> SingleValueMapper<bool>;
+
+    #[view(getQuadraticVoteTokens)]
+    #[storage_mapper("quadratic_vote_tokens")]
+    fn quadratic_vote_tokens(&self, proposal_id: u64, voter: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getVoteMode)]
+    #[storage_mapper("vote_mode")]
+    fn vote_mode(&self) -> SingleValueMapper<VoteMode>;
+
+    #[storage_mapper("pass_threshold")]
+    fn pass_threshold(&self) -> SingleValueMapper<u64>;
+
     #[view(getMinVoteWeight)]
     #[storage_mapper("min_vote_weight")]
     fn min_vote_weight(&self) -> SingleValueMapper<BigUint>;
@@ -173,7 +569,54 @@ pub trait ConfigModule {
     #[storage_mapper("voting_period_minutes")]
     fn voting_period_in_minutes(&self) -> SingleValueMapper<usize>;
 
+    #[view(getVotingDelayMinutes)]
+    #[storage_mapper("voting_delay_minutes")]
+    fn voting_delay_in_minutes(&self) -> SingleValueMapper<usize>;
+
+    #[view(getExecutionDelayMinutes)]
+    #[storage_mapper("execution_delay_minutes")]
+    fn execution_delay_in_minutes(&self) -> SingleValueMapper<usize>;
+
+    #[view(getGracePeriodMinutes)]
+    #[storage_mapper("grace_period_minutes")]
+    fn grace_period_in_minutes(&self) -> SingleValueMapper<usize>;
+
+    #[view(getMaxActiveProposals)]
+    #[storage_mapper("max_active_proposals")]
+    fn max_active_proposals(&self) -> SingleValueMapper<usize>;
+
+    #[view(getProposalCooldownMinutes)]
+    #[storage_mapper("proposal_cooldown_minutes")]
+    fn proposal_cooldown_in_minutes(&self) -> SingleValueMapper<usize>;
+
+    #[view(getProposalRateLimitExemptRoles)]
+    #[storage_mapper("proposal_rate_limit_exempt_roles")]
+    fn proposal_rate_limit_exempt_roles(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(getLateVoteWindowMinutes)]
+    #[storage_mapper("late_vote_window_minutes")]
+    fn late_vote_window_in_minutes(&self) -> SingleValueMapper<usize>;
+
+    #[view(getLateVoteExtensionMinutes)]
+    #[storage_mapper("late_vote_extension_minutes")]
+    fn late_vote_extension_in_minutes(&self) -> SingleValueMapper<usize>;
+
+    #[view(getMaxLateVoteExtensions)]
+    #[storage_mapper("max_late_vote_extensions")]
+    fn max_late_vote_extensions(&self) -> SingleValueMapper<usize>;
+
+    #[view(getProposalExtensions)]
+    #[storage_mapper("proposal_extensions")]
+    fn proposal_extensions(&self, proposal_id: u64) -> SingleValueMapper<usize>;
+
     #[view(getRestrictedVoteNonces)]
     #[storage_mapper("restricted_vote_nonces")]
     fn restricted_vote_nonces(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("vote_nonce_weights")]
+    fn vote_nonce_weights(&self) -> MapMapper<u64, BigUint>;
+
+    #[view(getVoteNonceWeightDefault)]
+    #[storage_mapper("vote_nonce_weight_default")]
+    fn vote_nonce_weight_default(&self) -> SingleValueMapper<BigUint>;
 }
@@ -2,7 +2,11 @@ multiversx_sc::imports!();
 
 use crate::config;
 use crate::governance;
+use crate::governance::delegation;
+use crate::governance::deposit;
+use crate::governance::escrow;
 use crate::governance::events;
+use crate::governance::poll;
 use crate::governance::proposal::ProposalStatus;
 use crate::permission;
 use crate::permission::ROLE_BUILTIN_DEVELOPER;
@@ -10,7 +14,15 @@ use crate::plug;
 
 #[multiversx_sc::module]
 pub trait ContractModule:
-    config::ConfigModule + governance::proposal::ProposalModule + permission::PermissionModule + events::GovEventsModule + plug::PlugModule
+    config::ConfigModule
+    + governance::proposal::ProposalModule
+    + permission::PermissionModule
+    + events::GovEventsModule
+    + plug::PlugModule
+    + delegation::DelegationModule
+    + deposit::DepositModule
+    + escrow::EscrowModule
+    + poll::PollModule
 {
     #[endpoint(lockContractStage)]
     fn lock_contract_stage_endpoint(&self, address: ManagedAddress) {
@@ -72,6 +84,8 @@ pub trait ContractModule:
             0,
             BigUint::zero(),
             permissions.into_vec(),
+            false,
+            None,
         );
 
         self.stage_current_proposal(&address).set(proposal.id);
@@ -129,7 +143,9 @@ pub trait ContractModule:
     fn cancel_stage_current_proposal(&self, address: &ManagedAddress, active_proposal_id: u64) {
         let active_proposal = self.proposals(active_proposal_id).get();
 
-        if self.get_proposal_status(&active_proposal) != ProposalStatus::Active {
+        let status = self.get_proposal_status(&active_proposal);
+
+        if status != ProposalStatus::Active && status != ProposalStatus::Pending {
             return;
         }
 
@@ -0,0 +1,203 @@
+multiversx_sc::imports!();
+
+use super::events;
+use super::proposal::Proposal;
+use crate::config::{self, UserId};
+use crate::plug;
+
+#[multiversx_sc::module]
+pub trait DelegationModule: config::ConfigModule + plug::PlugModule + events::GovEventsModule {
+    #[view(getDelegators)]
+    fn get_delegators_view(&self, delegate: ManagedAddress) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        let mut delegators = MultiValueEncoded::new();
+
+        for delegator_id in self.delegators(&delegate).iter() {
+            let delegator = self.users().get_user_address_unchecked(delegator_id);
+            let weight = self.get_delegated_weight(&delegator);
+
+            delegators.push((delegator, weight).into());
+        }
+
+        delegators
+    }
+
+    #[view(getDelegatedVoteWeight)]
+    fn get_delegated_vote_weight_view(&self, delegate: ManagedAddress) -> BigUint {
+        self.delegators(&delegate)
+            .iter()
+            .map(|delegator_id| self.get_delegated_weight(&self.users().get_user_address_unchecked(delegator_id)))
+            .fold(BigUint::zero(), |carry, weight| carry + weight)
+    }
+
+    fn commit_delegation(&self, delegator: &ManagedAddress, delegate: &ManagedAddress, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) {
+        let delegator_id = self.users().get_or_create_user(delegator);
+
+        if !self.delegate(delegator).is_empty() {
+            let previous_delegate = self.delegate(delegator).get();
+            self.delegators(&previous_delegate).swap_remove(&delegator_id);
+        }
+
+        self.delegate(delegator).set(delegate);
+        self.delegators(delegate).insert(delegator_id);
+
+        for payment in payments.into_iter() {
+            self.delegated_tokens(delegator).push(&payment);
+            self.guarded_vote_tokens(&payment.token_identifier, payment.token_nonce)
+                .update(|current| *current += &payment.amount);
+        }
+    }
+
+    fn revoke_delegation(&self, delegator: &ManagedAddress) {
+        require!(!self.delegate(delegator).is_empty(), "no delegation");
+
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(current_time >= self.delegation_lock(delegator).get(), "delegation is locked by an active proposal");
+
+        let delegate = self.delegate(delegator).take();
+        let delegator_id = self.users().get_user_id(delegator);
+        let mut returnables: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
+
+        for payment in self.delegated_tokens(delegator).iter() {
+            self.guarded_vote_tokens(&payment.token_identifier, payment.token_nonce)
+                .update(|current| *current -= &payment.amount);
+
+            returnables.push(payment);
+        }
+
+        self.delegators(&delegate).swap_remove(&delegator_id);
+        self.delegated_tokens(delegator).clear();
+        self.delegated_plug_weight(delegator).clear();
+        self.delegation_lock(delegator).clear();
+        self.undelegate_event(delegator.clone(), delegate);
+
+        if !returnables.is_empty() {
+            self.send().direct_multi(delegator, &returnables);
+        }
+    }
+
+    /// Calculates the not yet used delegation weight a voter would cast on the given proposal.
+    /// Includes the voter's own delegated tokens and the weight of everyone who delegated to the voter.
+    /// A delegator's weight can only be used once per proposal: whoever votes first (delegator or delegate) casts it.
+    fn get_delegation_vote_weight(&self, voter: &ManagedAddress, proposal_id: u64) -> BigUint {
+        let mut weight = BigUint::zero();
+        let voter_id = self.users().get_user_id(voter);
+
+        if voter_id != 0 && !self.delegation_votes(proposal_id).contains(&voter_id) {
+            weight += self.get_vote_weight_from_payments(&self.get_delegator_vote_payments(proposal_id, voter));
+        }
+
+        for delegator_id in self.delegators(voter).iter() {
+            if self.delegation_votes(proposal_id).contains(&delegator_id) {
+                continue;
+            }
+
+            let delegator = self.users().get_user_address_unchecked(delegator_id);
+            weight += self.get_vote_weight_from_payments(&self.get_delegator_vote_payments(proposal_id, &delegator));
+
+            if !self.has_user_plug_voted(proposal_id, &delegator) {
+                weight += self.delegated_plug_weight(&delegator).get();
+            }
+        }
+
+        weight
+    }
+
+    /// Casts the available delegation weight of a voter on the given proposal and marks it as used.
+    /// The delegated tokens stay locked until the proposal's voting period has ended.
+    /// Returns the weight that has been cast.
+    fn commit_delegation_votes(&self, voter: &ManagedAddress, proposal: &Proposal<Self::Api>) -> BigUint {
+        let weight = self.get_delegation_vote_weight(voter, proposal.id);
+        let voter_id = self.users().get_user_id(voter);
+
+        if voter_id != 0 && !self.delegated_tokens(voter).is_empty() {
+            self.use_delegation_vote(proposal, voter_id, voter);
+        }
+
+        for delegator_id in self.delegators(voter).iter() {
+            if self.delegation_votes(proposal.id).contains(&delegator_id) {
+                continue;
+            }
+
+            let delegator = self.users().get_user_address_unchecked(delegator_id);
+            self.use_delegation_vote(proposal, delegator_id, &delegator);
+
+            if !self.delegated_plug_weight(&delegator).is_empty() {
+                self.record_plug_vote(delegator, proposal.id);
+            }
+        }
+
+        weight
+    }
+
+    fn use_delegation_vote(&self, proposal: &Proposal<Self::Api>, delegator_id: UserId, delegator: &ManagedAddress) {
+        if !self.delegation_votes(proposal.id).insert(delegator_id) {
+            return;
+        }
+
+        for payment in self.get_delegator_vote_payments(proposal.id, delegator).iter() {
+            if payment.token_nonce != 0 && !self.lock_vote_tokens(&payment.token_identifier).get() {
+                self.get_proposal_nft_votes(proposal.id, &payment.token_identifier).insert(payment.token_nonce);
+            }
+        }
+
+        self.delegation_lock(delegator).update(|current| *current = (*current).max(proposal.ends_at));
+    }
+
+    /// Keeps the delegations used on a proposal locked until its extended voting period has ended.
+    fn extend_delegation_locks(&self, proposal: &Proposal<Self::Api>) {
+        for delegator_id in self.delegation_votes(proposal.id).iter() {
+            let delegator = self.users().get_user_address_unchecked(delegator_id);
+            self.delegation_lock(&delegator).update(|current| *current = (*current).max(proposal.ends_at));
+        }
+    }
+
+    /// Returns the delegated tokens of a delegator that may still vote on the given proposal.
+    /// NFTs of collections that are not locked on vote are skipped if their nonce has voted already.
+    fn get_delegator_vote_payments(&self, proposal_id: u64, delegator: &ManagedAddress) -> ManagedVec<EsdtTokenPayment<Self::Api>> {
+        let mut payments = ManagedVec::new();
+
+        for payment in self.delegated_tokens(delegator).iter() {
+            let is_returnable_nft = payment.token_nonce != 0 && !self.lock_vote_tokens(&payment.token_identifier).get();
+
+            if is_returnable_nft && self.get_proposal_nft_votes(proposal_id, &payment.token_identifier).contains(&payment.token_nonce) {
+                continue;
+            }
+
+            payments.push(payment);
+        }
+
+        payments
+    }
+
+    fn get_delegated_weight(&self, delegator: &ManagedAddress) -> BigUint {
+        let token_weight = self.get_vote_weight_from_payments(&self.delegated_tokens(delegator).iter().collect());
+
+        token_weight + self.delegated_plug_weight(delegator).get()
+    }
+
+    fn emit_delegate_event(&self, delegator: ManagedAddress, delegate: ManagedAddress) {
+        let weight = self.get_delegated_weight(&delegator);
+
+        self.delegate_event(delegator, delegate, weight);
+    }
+
+    #[view(getDelegate)]
+    #[storage_mapper("delegation:delegate")]
+    fn delegate(&self, delegator: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("delegation:delegators")]
+    fn delegators(&self, delegate: &ManagedAddress) -> UnorderedSetMapper<UserId>;
+
+    #[view(getDelegatedTokens)]
+    #[storage_mapper("delegation:tokens")]
+    fn delegated_tokens(&self, delegator: &ManagedAddress) -> VecMapper<EsdtTokenPayment>;
+
+    #[storage_mapper("delegation:plug_weight")]
+    fn delegated_plug_weight(&self, delegator: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("delegation:votes")]
+    fn delegation_votes(&self, proposal_id: u64) -> UnorderedSetMapper<UserId>;
+
+    #[storage_mapper("delegation:lock")]
+    fn delegation_lock(&self, delegator: &ManagedAddress) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,155 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use super::events;
+use super::proposal::{Proposal, ProposalStatus};
+use crate::config;
+use crate::permission;
+use crate::plug;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct ProposalDeposit<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub burn_on_slash: bool,
+}
+
+#[multiversx_sc::module]
+pub trait DepositModule: config::ConfigModule + plug::PlugModule + permission::PermissionModule + events::GovEventsModule {
+    fn try_change_proposal_deposit(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint, burn_on_slash: bool) {
+        require!(token_id.is_valid(), "invalid token id");
+        require!(amount > 0, "invalid deposit amount");
+        require!(!(burn_on_slash && token_id.is_egld()), "egld deposits can not be burned");
+
+        self.proposal_deposit().set(ProposalDeposit {
+            token_id,
+            amount,
+            burn_on_slash,
+        });
+    }
+
+    /// Splits the configured proposal deposit off the payments sent along a proposal.
+    /// EGLD deposits must be sent as the call value, ESDT deposits are deducted from the first fungible payment of the deposit token.
+    /// Returns the remaining payments, which count as vote weight, and the deposit if one is configured.
+    fn take_proposal_deposit(&self, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) -> (ManagedVec<EsdtTokenPayment<Self::Api>>, Option<ProposalDeposit<Self::Api>>) {
+        if self.proposal_deposit().is_empty() {
+            return (payments.clone(), None);
+        }
+
+        let deposit = self.proposal_deposit().get();
+
+        if deposit.token_id.is_egld() {
+            require!(self.call_value().egld_value().clone_value() == deposit.amount, "invalid proposal deposit");
+            return (payments.clone(), Some(deposit));
+        }
+
+        let mut remaining = ManagedVec::new();
+        let mut deducted = false;
+
+        for payment in payments.into_iter() {
+            let is_deposit_payment = !deducted && deposit.token_id == payment.token_identifier && payment.token_nonce == 0 && payment.amount >= deposit.amount;
+
+            if !is_deposit_payment {
+                remaining.push(payment);
+                continue;
+            }
+
+            deducted = true;
+
+            if payment.amount > deposit.amount {
+                remaining.push(EsdtTokenPayment::new(payment.token_identifier, 0, &payment.amount - &deposit.amount));
+            }
+        }
+
+        require!(deducted, "proposal deposit required");
+
+        (remaining, Some(deposit))
+    }
+
+    fn commit_proposal_deposit(&self, proposer: &ManagedAddress, proposal_id: u64, deposit: ProposalDeposit<Self::Api>) {
+        let proposer_id = self.users().get_or_create_user(proposer);
+
+        self.guard_proposal_deposit(&deposit.token_id, &deposit.amount, true);
+        self.withdrawable_voters(proposal_id).insert(proposer_id);
+        self.withdrawable_proposal_ids(proposer).insert(proposal_id);
+        self.proposal_deposits(proposal_id).set(deposit);
+    }
+
+    /// Returns the proposal deposit to the proposer if refunded, otherwise keeps it in the treasury or burns it as configured.
+    fn settle_proposal_deposit(&self, proposal: &Proposal<Self::Api>, refund: bool) {
+        if self.proposal_deposits(proposal.id).is_empty() {
+            return;
+        }
+
+        let deposit = self.proposal_deposits(proposal.id).take();
+
+        self.guard_proposal_deposit(&deposit.token_id, &deposit.amount, false);
+
+        if refund {
+            self.deposit_refund_event(proposal.proposer.clone(), proposal.id, deposit.amount.clone());
+            self.send().direct(&proposal.proposer, &deposit.token_id, 0, &deposit.amount);
+            return;
+        }
+
+        self.deposit_slash_event(proposal.proposer.clone(), proposal.id, deposit.amount.clone(), deposit.burn_on_slash);
+
+        if deposit.burn_on_slash {
+            let token_id = deposit.token_id.unwrap_esdt();
+            let amount = deposit.amount;
+            self.send().esdt_local_burn(&token_id, 0, &amount);
+
+            if self.is_gov_token(&token_id) {
+                self.gov_token_supply().update(|supply| *supply = if *supply > amount { &*supply - &amount } else { BigUint::zero() });
+            }
+        }
+    }
+
+    /// Only proposals that are still being voted on or have been defeated can be flagged, never ones that passed.
+    fn flag_proposal_spam(&self, proposal_id: u64, status: ProposalStatus) {
+        let caller = self.blockchain().get_caller();
+
+        require!(self.is_guardian(&caller), "caller must be guardian");
+        require!(!self.proposal_deposits(proposal_id).is_empty(), "proposal has no deposit");
+
+        let can_flag = matches!(status, ProposalStatus::Pending | ProposalStatus::Active | ProposalStatus::Defeated);
+
+        require!(can_flag, "proposal can not be flagged");
+
+        self.proposal_spam_flags(proposal_id).set(true);
+        self.flag_spam_event(caller, proposal_id);
+    }
+
+    fn require_egld_available(&self, amount: &BigUint) {
+        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        let available = balance - self.guarded_egld_deposits().get();
+
+        require!(amount <= &available, "not enough egld available");
+    }
+
+    fn guard_proposal_deposit(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint, guard: bool) {
+        let guarded = match token_id.is_egld() {
+            true => self.guarded_egld_deposits(),
+            false => self.guarded_vote_tokens(&token_id.clone().unwrap_esdt(), 0),
+        };
+
+        match guard {
+            true => guarded.update(|current| *current += amount),
+            false => guarded.update(|current| *current -= amount),
+        }
+    }
+
+    #[view(getProposalDeposit)]
+    #[storage_mapper("deposit:config")]
+    fn proposal_deposit(&self) -> SingleValueMapper<ProposalDeposit<Self::Api>>;
+
+    #[view(getProposalDepositOf)]
+    #[storage_mapper("deposit:proposals")]
+    fn proposal_deposits(&self, proposal_id: u64) -> SingleValueMapper<ProposalDeposit<Self::Api>>;
+
+    #[view(isProposalSpam)]
+    #[storage_mapper("deposit:spam")]
+    fn proposal_spam_flags(&self, proposal_id: u64) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("deposit:guarded_egld")]
+    fn guarded_egld_deposits(&self) -> SingleValueMapper<BigUint>;
+}
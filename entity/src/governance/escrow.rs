@@ -0,0 +1,181 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use super::events;
+use super::proposal::Proposal;
+use crate::config::{self, UserId, ESCROW_LOCK_MINUTES_MAX};
+
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct EscrowLock<M: ManagedTypeApi> {
+    pub token_id: TokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+    pub unlock_at: u64,
+}
+
+#[multiversx_sc::module]
+pub trait EscrowModule: config::ConfigModule + events::GovEventsModule {
+    /// Returns the current voting power of an address' escrow lock.
+    /// The power decays linearly from the full locked amount at the max lock duration down to 0 when the lock expires.
+    /// Locks of a token other than the current governance token have no voting power.
+    #[view(getEscrowVotingPower)]
+    fn get_escrow_voting_power_view(&self, address: ManagedAddress) -> BigUint {
+        self.get_escrow_voting_power(&address)
+    }
+
+    fn create_escrow_lock(&self, address: &ManagedAddress, payment: EsdtTokenPayment, lock_minutes: usize) {
+        require!(self.escrow_locks(address).is_empty(), "escrow already exists");
+        require!(payment.amount > 0, "nothing to lock");
+
+        let unlock_at = self.get_escrow_unlock_at(lock_minutes);
+
+        self.users().get_or_create_user(address);
+        self.guard_escrow_tokens(&payment.token_identifier, payment.token_nonce, &payment.amount, true);
+        self.escrow_locks(address).set(EscrowLock {
+            token_id: payment.token_identifier,
+            token_nonce: payment.token_nonce,
+            amount: payment.amount,
+            unlock_at,
+        });
+        self.emit_escrow_lock_event(address);
+    }
+
+    fn extend_escrow_lock(&self, address: &ManagedAddress, lock_minutes: usize) {
+        require!(!self.escrow_locks(address).is_empty(), "no escrow");
+
+        let unlock_at = self.get_escrow_unlock_at(lock_minutes);
+        let mut lock = self.escrow_locks(address).get();
+        require!(unlock_at > lock.unlock_at, "escrow can only be extended");
+
+        lock.unlock_at = unlock_at;
+        self.escrow_locks(address).set(lock);
+        self.emit_escrow_lock_event(address);
+    }
+
+    fn increase_escrow_lock(&self, address: &ManagedAddress, payment: EsdtTokenPayment) {
+        require!(!self.escrow_locks(address).is_empty(), "no escrow");
+        require!(payment.amount > 0, "nothing to lock");
+
+        let mut lock = self.escrow_locks(address).get();
+        require!(self.blockchain().get_block_timestamp() < lock.unlock_at, "escrow has expired");
+        require!(payment.token_identifier == lock.token_id && payment.token_nonce == lock.token_nonce, "invalid payment token");
+
+        self.guard_escrow_tokens(&lock.token_id, lock.token_nonce, &payment.amount, true);
+        lock.amount += payment.amount;
+        self.escrow_locks(address).set(lock);
+        self.emit_escrow_lock_event(address);
+    }
+
+    fn withdraw_escrow_lock(&self, address: &ManagedAddress) {
+        require!(!self.escrow_locks(address).is_empty(), "no escrow");
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let lock = self.escrow_locks(address).get();
+        require!(current_time >= lock.unlock_at, "escrow is still locked");
+        require!(current_time >= self.escrow_vote_lock(address).get(), "escrow is locked by an active proposal");
+
+        self.guard_escrow_tokens(&lock.token_id, lock.token_nonce, &lock.amount, false);
+        self.escrow_locks(address).clear();
+        self.escrow_vote_lock(address).clear();
+        self.escrow_withdraw_event(address.clone(), lock.amount.clone());
+
+        self.send().direct_esdt(address, &lock.token_id, lock.token_nonce, &lock.amount);
+    }
+
+    fn get_escrow_voting_power(&self, address: &ManagedAddress) -> BigUint {
+        if self.escrow_locks(address).is_empty() {
+            return BigUint::zero();
+        }
+
+        let lock = self.escrow_locks(address).get();
+
+        // locks of a previous governance token keep their tokens withdrawable but no longer grant voting power.
+        if !self.is_gov_token(&lock.token_id) {
+            return BigUint::zero();
+        }
+
+        let remaining_seconds = lock.unlock_at.saturating_sub(self.blockchain().get_block_timestamp());
+        let max_lock_seconds = ESCROW_LOCK_MINUTES_MAX as u64 * 60;
+
+        lock.amount * remaining_seconds / max_lock_seconds
+    }
+
+    /// Returns the escrow voting power a voter would cast on the given proposal.
+    /// An escrow can only be used once per proposal.
+    fn get_escrow_vote_weight(&self, voter: &ManagedAddress, proposal_id: u64) -> BigUint {
+        let voter_id = self.users().get_user_id(voter);
+
+        if voter_id == 0 || self.escrow_votes(proposal_id).contains(&voter_id) {
+            return BigUint::zero();
+        }
+
+        self.get_escrow_voting_power(voter)
+    }
+
+    /// Casts the escrow voting power of a voter on the given proposal and marks it as used.
+    /// The escrow can not be withdrawn until the proposal's voting period has ended.
+    /// Returns the weight that has been cast.
+    fn commit_escrow_votes(&self, voter: &ManagedAddress, proposal: &Proposal<Self::Api>) -> BigUint {
+        let weight = self.get_escrow_vote_weight(voter, proposal.id);
+
+        if weight == 0 {
+            return weight;
+        }
+
+        let voter_id = self.users().get_user_id(voter);
+
+        self.escrow_votes(proposal.id).insert(voter_id);
+        self.escrow_vote_lock(voter).update(|current| *current = (*current).max(proposal.ends_at));
+
+        weight
+    }
+
+    /// Keeps the escrows used on a proposal locked until its extended voting period has ended.
+    fn extend_escrow_vote_locks(&self, proposal: &Proposal<Self::Api>) {
+        for voter_id in self.escrow_votes(proposal.id).iter() {
+            let voter = self.users().get_user_address_unchecked(voter_id);
+            self.escrow_vote_lock(&voter).update(|current| *current = (*current).max(proposal.ends_at));
+        }
+    }
+
+    fn require_escrow_payment(&self) -> EsdtTokenPayment {
+        self.require_gov_token_set();
+
+        let payment = self.call_value().single_esdt();
+        let is_fungible_gov_token = payment.token_identifier == self.gov_token_id().get() && payment.token_nonce == 0;
+        require!(is_fungible_gov_token, "invalid payment token");
+
+        payment
+    }
+
+    fn get_escrow_unlock_at(&self, lock_minutes: usize) -> u64 {
+        require!(lock_minutes > 0 && lock_minutes <= ESCROW_LOCK_MINUTES_MAX, "invalid lock duration");
+
+        self.blockchain().get_block_timestamp() + lock_minutes as u64 * 60
+    }
+
+    fn guard_escrow_tokens(&self, token_id: &TokenIdentifier, token_nonce: u64, amount: &BigUint, guard: bool) {
+        let guarded = self.guarded_vote_tokens(token_id, token_nonce);
+
+        match guard {
+            true => guarded.update(|current| *current += amount),
+            false => guarded.update(|current| *current -= amount),
+        }
+    }
+
+    fn emit_escrow_lock_event(&self, address: &ManagedAddress) {
+        let lock = self.escrow_locks(address).get();
+
+        self.escrow_lock_event(address.clone(), lock.amount, lock.unlock_at);
+    }
+
+    #[view(getEscrow)]
+    #[storage_mapper("escrow:locks")]
+    fn escrow_locks(&self, address: &ManagedAddress) -> SingleValueMapper<EscrowLock<Self::Api>>;
+
+    #[storage_mapper("escrow:votes")]
+    fn escrow_votes(&self, proposal_id: u64) -> UnorderedSetMapper<UserId>;
+
+    #[storage_mapper("escrow:vote_lock")]
+    fn escrow_vote_lock(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+}
@@ -16,6 +16,9 @@ pub trait GovEventsModule {
             VoteType::Against => {
                 self.vote_against_event(voter, proposal.id, weight, poll_option);
             }
+            VoteType::Abstain => {
+                self.abstain_event(voter, proposal.id, weight, poll_option);
+            }
         }
     }
 
@@ -35,6 +38,10 @@ pub trait GovEventsModule {
         self.cancel_event(self.blockchain().get_caller(), proposal.id);
     }
 
+    fn emit_veto_event(&self, proposal: &Proposal<Self::Api>) {
+        self.veto_event(self.blockchain().get_caller(), proposal.id);
+    }
+
     fn emit_withdraw_event(&self, proposal: &Proposal<Self::Api>) {
         self.withdraw_event(self.blockchain().get_caller(), proposal.id);
     }
@@ -48,6 +55,12 @@ pub trait GovEventsModule {
     #[event("vote_against")]
     fn vote_against_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: u64, #[indexed] weight: BigUint, #[indexed] poll_option: u8);
 
+    #[event("abstain")]
+    fn abstain_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: u64, #[indexed] weight: BigUint, #[indexed] poll_option: u8);
+
+    #[event("poll_ballot")]
+    fn poll_ballot_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: u64, #[indexed] weight: BigUint, options: ManagedVec<u8>);
+
     #[event("sign")]
     fn sign_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: u64, #[indexed] poll_option: u8);
 
@@ -60,6 +73,39 @@ pub trait GovEventsModule {
     #[event("cancel")]
     fn cancel_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: u64);
 
+    #[event("veto")]
+    fn veto_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: u64);
+
+    #[event("extend_voting")]
+    fn extend_voting_event(&self, #[indexed] proposal: u64, #[indexed] ends_at: u64, #[indexed] extensions: usize);
+
     #[event("withdraw")]
     fn withdraw_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: u64);
+
+    #[event("delegate")]
+    fn delegate_event(&self, #[indexed] caller: ManagedAddress, #[indexed] delegate: ManagedAddress, #[indexed] weight: BigUint);
+
+    #[event("undelegate")]
+    fn undelegate_event(&self, #[indexed] caller: ManagedAddress, #[indexed] delegate: ManagedAddress);
+
+    #[event("change_vote")]
+    fn change_vote_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: u64, #[indexed] vote_type: VoteType, #[indexed] weight: BigUint);
+
+    #[event("revoke_vote")]
+    fn revoke_vote_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: u64, #[indexed] weight: BigUint);
+
+    #[event("escrow_lock")]
+    fn escrow_lock_event(&self, #[indexed] caller: ManagedAddress, #[indexed] amount: BigUint, #[indexed] unlock_at: u64);
+
+    #[event("escrow_withdraw")]
+    fn escrow_withdraw_event(&self, #[indexed] caller: ManagedAddress, #[indexed] amount: BigUint);
+
+    #[event("deposit_refund")]
+    fn deposit_refund_event(&self, #[indexed] proposer: ManagedAddress, #[indexed] proposal: u64, #[indexed] amount: BigUint);
+
+    #[event("deposit_slash")]
+    fn deposit_slash_event(&self, #[indexed] proposer: ManagedAddress, #[indexed] proposal: u64, #[indexed] amount: BigUint, #[indexed] burned: bool);
+
+    #[event("flag_spam")]
+    fn flag_spam_event(&self, #[indexed] caller: ManagedAddress, #[indexed] proposal: u64);
 }
@@ -1,21 +1,39 @@
 multiversx_sc::imports!();
 
 use crate::config::{
-    self, GAS_LIMIT_SET_TOKEN_ROLES, MIN_PROPOSAL_VOTE_WEIGHT_DEFAULT, POLL_MAX_OPTIONS, QUORUM_DEFAULT, TOKEN_MAX_DECIMALS, VOTING_PERIOD_MINUTES_DEFAULT,
+    self, GAS_LIMIT_SET_TOKEN_ROLES, MIN_PROPOSAL_VOTE_WEIGHT_DEFAULT, QUORUM_DEFAULT, TOKEN_MAX_DECIMALS, VOTING_PERIOD_MINUTES_DEFAULT,
 };
 use crate::permission::{self, ROLE_BUILTIN_LEADER};
 use crate::plug;
 use errors::ALREADY_VOTED_WITH_TOKEN;
-use proposal::{Action, ProposalStatus, VoteType};
+use deposit::ProposalDeposit;
+use poll::{Poll, PollMode};
+use proposal::{Action, ProposalStatus, QuorumMode, VoteMode, VoteType};
+use signed_vote::SignedVote;
 
+pub mod delegation;
+pub mod deposit;
 pub mod errors;
+pub mod escrow;
 pub mod events;
+pub mod poll;
 pub mod proposal;
+pub mod signed_vote;
 pub mod token;
 
 #[multiversx_sc::module]
 pub trait GovernanceModule:
-    config::ConfigModule + plug::PlugModule + permission::PermissionModule + events::GovEventsModule + proposal::ProposalModule + token::TokenModule
+    config::ConfigModule
+    + plug::PlugModule
+    + permission::PermissionModule
+    + events::GovEventsModule
+    + proposal::ProposalModule
+    + token::TokenModule
+    + delegation::DelegationModule
+    + deposit::DepositModule
+    + escrow::EscrowModule
+    + poll::PollModule
+    + signed_vote::SignedVoteModule
 {
     fn init_governance_module(&self) {
         self.next_proposal_id().set_if_empty(1);
@@ -27,6 +45,7 @@ pub trait GovernanceModule:
     fn configure_governance_token(&self, gov_token_id: TokenIdentifier, supply: BigUint, lock_vote_tokens: bool) {
         self.try_change_governance_token(&gov_token_id);
         self.lock_vote_tokens(&gov_token_id).set(lock_vote_tokens);
+        self.gov_token_supply().set(&supply);
 
         if supply == 0 {
             return;
@@ -79,6 +98,8 @@ pub trait GovernanceModule:
 
         let removed_gov_token = self.gov_token_id().take();
         self.lock_vote_tokens(&removed_gov_token).clear();
+        self.gov_token_supply().clear();
+        self.quorum_percent().clear();
     }
 
     /// Change the governance default quorum.
@@ -89,6 +110,134 @@ pub trait GovernanceModule:
         self.try_change_quorum(value);
     }
 
+    /// Change the quorum to a percentage of the circulating governance token supply.
+    /// The circulating supply is tracked through minting and burning and excludes tokens held by the treasury.
+    /// A value of 0 falls back to the absolute quorum.
+    /// Can only be called by the contract itself.
+    #[endpoint(changeQuorumPercent)]
+    fn change_quorum_percent_endpoint(&self, value: u64) {
+        self.require_caller_self();
+        self.try_change_quorum_percent(value);
+    }
+
+    /// Let the quorum follow the average turnout of the most recently finished proposals, bounded by a minimum and maximum.
+    /// Until enough proposals have finished, the configured quorum is used within the same bounds.
+    /// Each proposal keeps the quorum in effect when it was created.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - min: lower bound of the quorum
+    ///     - max: upper bound of the quorum
+    ///     - sample_size: amount of recently finished proposals the average turnout is taken from
+    #[endpoint(setDynamicQuorum)]
+    fn set_dynamic_quorum_endpoint(&self, min: BigUint, max: BigUint, sample_size: usize) {
+        self.require_caller_self();
+        self.try_change_dynamic_quorum(min, max, sample_size);
+    }
+
+    /// Disable the dynamic quorum and fall back to the configured quorum.
+    /// The recorded turnout history is cleared, so a dynamic quorum set later only follows proposals created afterwards.
+    /// Can only be called by the contract itself.
+    #[endpoint(removeDynamicQuorum)]
+    fn remove_dynamic_quorum_endpoint(&self) {
+        self.require_caller_self();
+        self.dynamic_quorum().clear();
+        self.dynamic_quorum_pending_proposals().clear();
+        self.proposal_turnouts().clear();
+    }
+
+    /// Change the percentage of FOR votes required for a proposal to pass, e.g. 66 for a two-thirds supermajority.
+    /// Must be between 50 and 100.
+    /// Can only be called by the contract itself.
+    #[endpoint(changePassThreshold)]
+    fn change_pass_threshold_endpoint(&self, value: u64) {
+        self.require_caller_self();
+        self.try_change_pass_threshold(value);
+    }
+
+    /// Change the minimum delay in minutes between a proposal's success and its execution.
+    /// Policies can require a longer delay for specific permissions.
+    /// Can only be called by the contract itself.
+    #[endpoint(changeExecutionDelay)]
+    fn change_execution_delay_endpoint(&self, minutes: usize) {
+        self.require_caller_self();
+        self.try_change_execution_delay_in_minutes(minutes);
+    }
+
+    /// Change the period in minutes after the voting period and execution delay in which a succeeded proposal can be executed.
+    /// Afterwards, the proposal expires. A value of 0 disables expiry.
+    /// Can only be called by the contract itself.
+    #[endpoint(changeGracePeriod)]
+    fn change_grace_period_endpoint(&self, minutes: usize) {
+        self.require_caller_self();
+        self.try_change_grace_period_in_minutes(minutes);
+    }
+
+    /// Change which votes count toward the quorum: FOR only, FOR and AGAINST, or all votes including ABSTAIN.
+    /// Can only be called by the contract itself.
+    #[endpoint(changeQuorumMode)]
+    fn change_quorum_mode_endpoint(&self, mode: QuorumMode) {
+        self.require_caller_self();
+        self.quorum_mode().set(mode);
+    }
+
+    /// Change how repeated votes of the same address on a proposal are handled: added up, rejected or replacing the previous ballot.
+    /// Can only be called by the contract itself.
+    #[endpoint(changeVoteMode)]
+    fn change_vote_mode_endpoint(&self, mode: VoteMode) {
+        self.require_caller_self();
+        self.vote_mode().set(mode);
+    }
+
+    /// Enable or disable quadratic voting.
+    /// When enabled, a voter's weight on a proposal is the square root of all tokens the voter has committed to it.
+    /// The quorum is compared against this weight, while the deposited tokens remain fully withdrawable.
+    /// Can only be called by the contract itself.
+    #[endpoint(changeQuadraticVoting)]
+    fn change_quadratic_voting_endpoint(&self, enabled: bool) {
+        self.require_caller_self();
+        self.quadratic_voting().set(enabled);
+    }
+
+    /// Register a token that can be used to vote, or update its settings.
+    /// The multiplier is a percentage of the normalized amount (100 = 1x). Amounts are normalized from the token's decimals
+    /// to the decimals of the governance token, which therefore must be registered before any other token.
+    /// NFTs, SFTs & MetaESDTs of the token are only locked on vote if lock_vote_tokens is set to true.
+    /// Can only be called by the contract itself.
+    #[endpoint(setVoteToken)]
+    fn set_vote_token_endpoint(&self, token_id: TokenIdentifier, multiplier: u64, decimals: u8, lock_vote_tokens: bool) {
+        self.require_caller_self();
+        self.require_gov_token_set();
+        self.try_set_vote_token(token_id.clone(), multiplier, decimals);
+        self.lock_vote_tokens(&token_id).set(lock_vote_tokens);
+    }
+
+    /// Remove a registered vote token. The governance token can only be removed once no other token is registered.
+    /// Tokens deposited for votes or delegations stay withdrawable.
+    /// Can only be called by the contract itself.
+    #[endpoint(removeVoteToken)]
+    fn remove_vote_token_endpoint(&self, token_id: TokenIdentifier) {
+        self.require_caller_self();
+        self.try_remove_vote_token(&token_id);
+    }
+
+    /// Weigh a locked MetaESDT vote token by its remaining lock time, read from the unlock epoch in its attributes.
+    /// The unlock epoch is decoded as a big-endian integer of up to 8 bytes at the given attributes offset.
+    /// Positions locked for max_lock_epochs or longer count with their full amount, expired positions have no weight.
+    /// Can only be called by the contract itself.
+    #[endpoint(setLockedVoteTokenLayout)]
+    fn set_locked_vote_token_layout_endpoint(&self, token_id: TokenIdentifier, unlock_epoch_offset: usize, unlock_epoch_length: usize, max_lock_epochs: u64) {
+        self.require_caller_self();
+        self.try_set_locked_vote_token_layout(&token_id, unlock_epoch_offset, unlock_epoch_length, max_lock_epochs);
+    }
+
+    /// Remove the locked token layout of a vote token, which then counts by its amount again.
+    /// Can only be called by the contract itself.
+    #[endpoint(removeLockedVoteTokenLayout)]
+    fn remove_locked_vote_token_layout_endpoint(&self, token_id: TokenIdentifier) {
+        self.require_caller_self();
+        self.locked_vote_token_layout(&token_id).clear();
+    }
+
     /// Change the minimum weight required to vote.
     /// Can only be called by the contract itself.
     #[endpoint(changeMinVoteWeight)]
@@ -115,6 +264,96 @@ pub trait GovernanceModule:
         self.try_change_voting_period_in_minutes(value);
     }
 
+    /// Change the delay between creating a proposal and the start of its voting period.
+    /// While pending, the proposal can be reviewed, signed and canceled, but not voted on.
+    /// The proposer's weight is recorded on creation and only counts once voting has started.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - value: voting delay duration **in minutes**
+    #[endpoint(changeVotingDelayMinutes)]
+    fn change_voting_delay_in_minutes_endpoint(&self, value: usize) {
+        self.require_caller_self();
+        self.try_change_voting_delay_in_minutes(value);
+    }
+
+    /// Change the maximum number of pending or active proposals a single proposer can have at once.
+    /// A value of 0 disables the limit.
+    /// Can only be called by the contract itself.
+    #[endpoint(changeMaxActiveProposals)]
+    fn change_max_active_proposals_endpoint(&self, value: usize) {
+        self.require_caller_self();
+        self.max_active_proposals().set(value);
+    }
+
+    /// Change the minimum time a proposer has to wait between creating two proposals.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - value: cooldown duration **in minutes**
+    #[endpoint(changeProposalCooldownMinutes)]
+    fn change_proposal_cooldown_in_minutes_endpoint(&self, value: usize) {
+        self.require_caller_self();
+        self.try_change_proposal_cooldown_in_minutes(value);
+    }
+
+    /// Set the roles whose members are exempt from the proposal rate limits, e.g. the leader role.
+    /// Can only be called by the contract itself.
+    #[endpoint(setProposalRateLimitExemptRoles)]
+    fn set_proposal_rate_limit_exempt_roles_endpoint(&self, roles: MultiValueEncoded<ManagedBuffer>) {
+        self.require_caller_self();
+        self.proposal_rate_limit_exempt_roles().clear();
+        self.proposal_rate_limit_exempt_roles().extend(roles);
+    }
+
+    /// Configure the anti-sniping extension of the voting period.
+    /// A vote cast within the final window that flips whether a proposal has sufficient votes extends its voting period.
+    /// A window of 0 disables the extension.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - window_minutes: final part of the voting period in which late votes extend it **in minutes**
+    ///     - extension_minutes: duration each extension adds to the voting period **in minutes**
+    ///     - max_extensions: maximum number of extensions per proposal
+    #[endpoint(changeLateVoteExtension)]
+    fn change_late_vote_extension_endpoint(&self, window_minutes: usize, extension_minutes: usize, max_extensions: usize) {
+        self.require_caller_self();
+        self.try_change_late_vote_extension(window_minutes, extension_minutes, max_extensions);
+    }
+
+    /// Enable emergency proposals with their own voting period, quorum, pass threshold and execution delay.
+    /// The quorum and pass threshold only apply if they are higher than the ones of regular proposals.
+    /// Emergency proposals skip the voting delay and their execution delay starts as soon as they succeed.
+    /// Can only be called by the contract itself.
+    /// Arguments:
+    ///     - voting_period_minutes: voting period of emergency proposals **in minutes**
+    ///     - quorum: minimum quorum of emergency proposals
+    ///     - pass_threshold: minimum percentage of FOR votes for emergency proposals to pass
+    ///     - execution_delay_minutes: execution delay of emergency proposals **in minutes**, replacing the regular and policy execution delays
+    #[endpoint(setEmergencyConfig)]
+    fn set_emergency_config_endpoint(&self, voting_period_minutes: usize, quorum: BigUint, pass_threshold: u64, execution_delay_minutes: usize) {
+        self.require_caller_self();
+        self.try_change_emergency_config(voting_period_minutes, quorum, pass_threshold, execution_delay_minutes);
+    }
+
+    /// Disable emergency proposals.
+    /// Can only be called by the contract itself.
+    #[endpoint(removeEmergencyConfig)]
+    fn remove_emergency_config_endpoint(&self) {
+        self.require_caller_self();
+        self.emergency_config().clear();
+    }
+
+    /// Set the permissions emergency proposals are allowed to invoke. Replaces the previously allowed permissions.
+    /// Can only be called by the contract itself.
+    #[endpoint(setEmergencyPermissions)]
+    fn set_emergency_permissions_endpoint(&self, permissions: MultiValueEncoded<ManagedBuffer>) {
+        self.require_caller_self();
+        self.emergency_permissions().clear();
+
+        for permission in permissions.into_iter() {
+            require!(self.permissions().contains(&permission), "permission does not exist");
+            self.emergency_permissions().insert(permission);
+        }
+    }
+
     /// Set token nonces that are allowed to vote.
     /// Can only be called by the contract itself.
     #[endpoint(setRestrictedVoteNonces)]
@@ -124,6 +363,45 @@ pub trait GovernanceModule:
         self.restricted_vote_nonces().extend(nonces.into_iter());
     }
 
+    /// Set the per-unit vote weights of governance token nonces in bulk, e.g. to weigh NFT rarity tiers.
+    /// Existing entries are overwritten and a weight of 0 removes the nonce from the table.
+    /// While the table is not empty, unlisted nonces count with the default weight.
+    /// Returned NFTs still vote only once per nonce and proposal, with the weight their nonce had at the time of the vote.
+    /// Can only be called by the contract itself.
+    #[endpoint(setVoteNonceWeights)]
+    fn set_vote_nonce_weights_endpoint(&self, weights: MultiValueEncoded<MultiValue2<u64, BigUint>>) {
+        self.require_caller_self();
+        self.try_set_vote_nonce_weights(weights);
+    }
+
+    /// Change the per-unit vote weight of governance token nonces missing from the nonce weight table.
+    /// A weight of 0 excludes unlisted nonces from voting.
+    /// Can only be called by the contract itself.
+    #[endpoint(changeVoteNonceWeightDefault)]
+    fn change_vote_nonce_weight_default_endpoint(&self, weight: BigUint) {
+        self.require_caller_self();
+        self.vote_nonce_weight_default().set(weight);
+    }
+
+    /// Require a deposit in EGLD or any fungible ESDT to create a proposal.
+    /// The deposit is refunded on withdrawal once the proposal reaches quorum or passes.
+    /// Otherwise, or if a guardian flagged the proposal as spam, it is kept by the treasury or burned if burn_on_slash is set.
+    /// Burning requires the `ESDTRoleLocalBurn` role for the deposit token.
+    /// Can only be called by the contract itself.
+    #[endpoint(setProposalDeposit)]
+    fn set_proposal_deposit_endpoint(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint, burn_on_slash: bool) {
+        self.require_caller_self();
+        self.try_change_proposal_deposit(token_id, amount, burn_on_slash);
+    }
+
+    /// Remove the proposal deposit requirement. Deposits of existing proposals are still settled.
+    /// Can only be called by the contract itself.
+    #[endpoint(removeProposalDeposit)]
+    fn remove_proposal_deposit_endpoint(&self) {
+        self.require_caller_self();
+        self.proposal_deposit().clear();
+    }
+
     /// Set the address of the plug smart contract.
     /// Can only be called by the contract itself.
     /// Can only be called once.
@@ -150,6 +428,7 @@ pub trait GovernanceModule:
     ///     - token id must be equal to configured governance token id
     ///     - amount must be greater than the min_propose_weight
     ///     - amount will be used to vote in favor (FOR) the proposal
+    ///     - if a proposal deposit is configured, it is deducted from the payments or must be sent as EGLD
     /// Returns an incremental proposal id
     #[payable("*")]
     #[endpoint(propose)]
@@ -161,9 +440,78 @@ pub trait GovernanceModule:
         actions_hash: ManagedBuffer,
         option_id: u8,
         permissions: MultiValueManagedVec<ManagedBuffer>,
+    ) -> u64 {
+        self.submit_proposal(trusted_host_id, content_hash, content_sig, actions_hash, option_id, permissions.into_vec(), false, None)
+    }
+
+    /// Create an emergency proposal to respond quickly, e.g. to an exploit of a contract the entity controls.
+    /// Emergency proposals use the emergency voting period, quorum and pass threshold and succeed as soon as these are reached.
+    /// Only the permissions allowed for emergencies can be invoked.
+    /// Arguments and payments are the same as for regular proposals.
+    #[payable("*")]
+    #[endpoint(proposeEmergency)]
+    fn propose_emergency_endpoint(
+        &self,
+        trusted_host_id: ManagedBuffer,
+        content_hash: ManagedBuffer,
+        content_sig: ManagedBuffer,
+        actions_hash: ManagedBuffer,
+        option_id: u8,
+        permissions: MultiValueManagedVec<ManagedBuffer>,
+    ) -> u64 {
+        self.submit_proposal(trusted_host_id, content_hash, content_sig, actions_hash, option_id, permissions.into_vec(), true, None)
+    }
+
+    /// Create a multiple-choice poll, optionally with a set of actions per option.
+    /// Only the actions of the winning option can be executed once voting has ended.
+    /// Arguments:
+    ///     - trusted_host_id: a unique id given by the trusted host
+    ///     - content_hash: the hash of the proposed content to verify integrity on the frontend
+    ///     - content_sig: signature provided by the trusted host, also covering the option hashes and option actions hashes
+    ///     - mode: how voters select options and how the winner is determined: single choice, approval or ranked choice
+    ///     - option_count: the amount of options, between 2 and 20
+    ///     - option_hashes: the hash of each option's content in the order of the option ids. leave empty if not needed
+    ///     - option_actions_hashes: the hash of each option's serialized actions in the order of the option ids. leave empty if no actions attached
+    ///     - permissions (optional): a list of permissions (their unique names) any option's actions may require
+    /// Payment (optional): same as for regular proposals. Voters, including the proposer, cast their ballot via votePoll.
+    /// Returns an incremental proposal id
+    #[payable("*")]
+    #[endpoint(proposePoll)]
+    fn propose_poll_endpoint(
+        &self,
+        trusted_host_id: ManagedBuffer,
+        content_hash: ManagedBuffer,
+        content_sig: ManagedBuffer,
+        mode: PollMode,
+        option_count: u8,
+        option_hashes: ManagedVec<ManagedBuffer>,
+        option_actions_hashes: ManagedVec<ManagedBuffer>,
+        permissions: MultiValueManagedVec<ManagedBuffer>,
+    ) -> u64 {
+        let actions_hash = self.calculate_poll_actions_hash(&option_actions_hashes);
+        let poll = Poll {
+            mode,
+            option_count,
+            option_hashes,
+            option_actions_hashes,
+        };
+
+        self.submit_proposal(trusted_host_id, content_hash, content_sig, actions_hash, 0, permissions.into_vec(), false, Some(poll))
+    }
+
+    fn submit_proposal(
+        &self,
+        trusted_host_id: ManagedBuffer,
+        content_hash: ManagedBuffer,
+        content_sig: ManagedBuffer,
+        actions_hash: ManagedBuffer,
+        option_id: u8,
+        permissions: ManagedVec<ManagedBuffer>,
+        is_emergency: bool,
+        poll: Option<Poll<Self::Api>>,
     ) -> u64 {
         let caller = self.blockchain().get_caller();
-        let payments = self.call_value().all_esdt_transfers().clone_value();
+        let (payments, deposit) = self.take_proposal_deposit(&self.call_value().all_esdt_transfers());
 
         self.require_payments_with_gov_token(&payments);
         self.require_vote_tokens_allowed(&payments);
@@ -173,12 +521,15 @@ pub trait GovernanceModule:
                 .with_callback(self.callbacks().propose_async_callback(
                     caller,
                     payments,
+                    deposit,
                     trusted_host_id,
                     content_hash,
                     content_sig,
                     actions_hash,
                     option_id,
-                    permissions.into_vec(),
+                    permissions,
+                    is_emergency,
+                    poll,
                 ))
                 .call_and_exit();
         }
@@ -193,11 +544,17 @@ pub trait GovernanceModule:
             actions_hash,
             option_id,
             payment_weight,
-            permissions.into_vec(),
+            permissions,
+            is_emergency,
+            poll,
         );
 
         self.commit_vote_payments(&caller, proposal.id.clone(), &payments);
 
+        if let Some(deposit) = deposit {
+            self.commit_proposal_deposit(&caller, proposal.id, deposit);
+        }
+
         proposal.id
     }
 
@@ -209,12 +566,15 @@ pub trait GovernanceModule:
         &self,
         original_caller: ManagedAddress,
         original_payments: ManagedVec<EsdtTokenPayment<Self::Api>>,
+        deposit: Option<ProposalDeposit<Self::Api>>,
         trusted_host_id: ManagedBuffer,
         content_hash: ManagedBuffer,
         content_sig: ManagedBuffer,
         actions_hash: ManagedBuffer,
         option_id: u8,
         permissions: ManagedVec<ManagedBuffer>,
+        is_emergency: bool,
+        poll: Option<Poll<Self::Api>>,
         #[call_result] result: ManagedAsyncCallResult<BigUint>,
     ) -> u64 {
         let proposal_id = match result {
@@ -233,10 +593,16 @@ pub trait GovernanceModule:
                     option_id,
                     total_weight,
                     permissions,
+                    is_emergency,
+                    poll,
                 );
 
                 self.commit_vote_payments(&original_caller, proposal.id, &original_payments);
 
+                if let Some(deposit) = deposit {
+                    self.commit_proposal_deposit(&original_caller, proposal.id, deposit);
+                }
+
                 if self.is_plugged() {
                     self.record_plug_vote(original_caller, proposal.id);
                 }
@@ -270,6 +636,7 @@ pub trait GovernanceModule:
         self.commit_vote_payments(&caller, proposal_id, &payments);
 
         if self.is_plugged() {
+            self.require_vote_mode_allows_ballot(&caller, proposal_id);
             self.call_plug_vote_weight_async()
                 .with_callback(self.callbacks().vote_async_callback(caller, payment_weight, proposal_id, VoteType::For, option_id))
                 .call_and_exit();
@@ -297,6 +664,7 @@ pub trait GovernanceModule:
         self.commit_vote_payments(&caller, proposal_id, &payments);
 
         if self.is_plugged() {
+            self.require_vote_mode_allows_ballot(&caller, proposal_id);
             self.call_plug_vote_weight_async()
                 .with_callback(
                     self.callbacks()
@@ -308,7 +676,100 @@ pub trait GovernanceModule:
         self.vote(caller, proposal_id, VoteType::Against, payment_weight, option_id);
     }
 
-    /// Vote for or against a proposal via an asynchronous callback.
+    /// Abstain from voting on a proposal.
+    /// The weight counts toward the quorum depending on the quorum mode, but never toward the pass ratio.
+    /// Payment (optional):
+    ///     - token id must be equal to configured governance token id
+    ///     - amount must be greater than the min_vote_weight
+    ///     - ESDTs and SFTs will be deposited and locked until the voting period has ended
+    ///     - NFTs will be recorded as a vote and immediately returned
+    #[payable("*")]
+    #[endpoint(voteAbstain)]
+    fn vote_abstain_endpoint(&self, proposal_id: u64, opt_option_id: OptionalValue<u8>) {
+        let caller = self.blockchain().get_caller();
+        let option_id = opt_option_id.into_option().unwrap_or_default();
+        let payments = self.call_value().all_esdt_transfers();
+        let payment_weight = self.get_vote_weight_from_payments(&payments);
+
+        self.require_payments_with_gov_token(&payments);
+        self.require_vote_tokens_allowed(&payments);
+        self.commit_vote_payments(&caller, proposal_id, &payments);
+
+        if self.is_plugged() {
+            self.require_vote_mode_allows_ballot(&caller, proposal_id);
+            self.call_plug_vote_weight_async()
+                .with_callback(
+                    self.callbacks()
+                        .vote_async_callback(caller, payment_weight, proposal_id, VoteType::Abstain, option_id),
+                )
+                .call_and_exit();
+        }
+
+        self.vote(caller, proposal_id, VoteType::Abstain, payment_weight, option_id);
+    }
+
+    /// Cast a ballot on a poll by selecting one option, several options for approval polls, or ranking options for ranked polls.
+    /// The ballot carries the caller's FOR weight on the proposal that is not bound to a single poll option.
+    /// Can be cast once per proposal.
+    /// Payment (optional): same as for voteFor, the weight is voted FOR the proposal and added to the ballot.
+    #[payable("*")]
+    #[endpoint(votePoll)]
+    fn vote_poll_endpoint(&self, proposal_id: u64, options: MultiValueManagedVec<u8>) {
+        let caller = self.blockchain().get_caller();
+        let payments = self.call_value().all_esdt_transfers();
+        let payment_weight = self.get_vote_weight_from_payments(&payments);
+
+        self.require_payments_with_gov_token(&payments);
+        self.require_vote_tokens_allowed(&payments);
+        self.commit_vote_payments(&caller, proposal_id, &payments);
+
+        if self.is_plugged() {
+            self.require_vote_mode_allows_ballot(&caller, proposal_id);
+            self.call_plug_vote_weight_async()
+                .with_callback(self.callbacks().vote_poll_async_callback(caller, payment_weight, proposal_id, options.into_vec()))
+                .call_and_exit();
+        }
+
+        self.vote_poll(caller, proposal_id, payment_weight, options.into_vec());
+    }
+
+    /// Submit a batch of votes signed by voters off-chain, so voters without EGLD for gas can vote via a relayer.
+    /// Each vote is cast with all of the voter's vote-escrowed and delegated weight deposited in the contract, which must reach the signed min weight.
+    /// Signed votes must be enabled by the trusted host setting the chain id first.
+    /// Fails entirely if any of the votes is invalid.
+    /// Arguments:
+    ///     - votes: the signed votes, each signed by the voter with a nonce greater than their last used one
+    #[endpoint(submitSignedVotes)]
+    fn submit_signed_votes_endpoint(&self, votes: MultiValueEncoded<SignedVote<Self::Api>>) {
+        for vote in votes.into_iter() {
+            self.require_valid_signed_vote(&vote);
+
+            let available_weight = self.get_delegation_vote_weight(&vote.voter, vote.proposal_id) + self.get_escrow_vote_weight(&vote.voter, vote.proposal_id);
+            require!(available_weight >= vote.min_weight, "insufficient vote weight");
+
+            self.vote(vote.voter, vote.proposal_id, vote.vote_type, BigUint::zero(), vote.option_id);
+        }
+    }
+
+    /// Change the caller's vote on an active proposal.
+    /// All of the caller's weight on the proposal is moved to the given vote type.
+    #[endpoint(changeVote)]
+    fn change_vote_endpoint(&self, proposal_id: u64, vote_type: VoteType) {
+        let caller = self.blockchain().get_caller();
+
+        self.change_vote(caller, proposal_id, vote_type);
+    }
+
+    /// Revoke the caller's vote on an active proposal.
+    /// Removes all of the caller's weight from the proposal and returns the deposited vote tokens right away.
+    #[endpoint(revokeVote)]
+    fn revoke_vote_endpoint(&self, proposal_id: u64) {
+        let caller = self.blockchain().get_caller();
+
+        self.revoke_vote(caller, proposal_id);
+    }
+
+    /// Vote for, against or abstain on a proposal via an asynchronous callback.
     /// The callback result must return the original caller's vote weight.
     /// Used majorly via the plugging feature.
     #[callback]
@@ -323,14 +784,13 @@ pub trait GovernanceModule:
     ) {
         match result {
             ManagedAsyncCallResult::Ok(vote_weight) => {
+                // plug weight is cast once per proposal: a replaced ballot keeps the plug weight it has been cast with.
                 let total_weight = if self.has_user_plug_voted(proposal_id, &original_caller) {
                     original_payment_weight
                 } else {
                     &original_payment_weight + &vote_weight
                 };
 
-                require!(total_weight > 0, "can not vote with 0 weight");
-
                 self.vote(original_caller.clone(), proposal_id, vote_type, total_weight, option_id);
 
                 if self.is_plugged() {
@@ -343,6 +803,34 @@ pub trait GovernanceModule:
         };
     }
 
+    /// Cast a poll ballot via an asynchronous callback.
+    /// The callback result must return the original caller's vote weight.
+    #[callback]
+    fn vote_poll_async_callback(
+        &self,
+        original_caller: ManagedAddress,
+        original_payment_weight: BigUint,
+        proposal_id: u64,
+        options: ManagedVec<u8>,
+        #[call_result] result: ManagedAsyncCallResult<BigUint>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(vote_weight) => {
+                let total_weight = if self.has_user_plug_voted(proposal_id, &original_caller) {
+                    original_payment_weight
+                } else {
+                    &original_payment_weight + &vote_weight
+                };
+
+                self.vote_poll(original_caller.clone(), proposal_id, total_weight, options);
+                self.record_plug_vote(original_caller, proposal_id);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                sc_panic!("failed to retrieve caller vote weight");
+            }
+        };
+    }
+
     /// Sign a proposal, optionally with a poll option.
     /// This is often required by role members to approve actions protected by policies.
     #[endpoint(sign)]
@@ -361,13 +849,30 @@ pub trait GovernanceModule:
         let actions = actions.into_vec();
         let actions_hash = self.calculate_actions_hash(&actions);
         let mut proposal = self.proposals(proposal_id).get();
-        require!(proposal.actions_hash == actions_hash, "actions have been corrupted");
+        let has_poll_actions = self.has_poll_actions(proposal_id);
+
+        let executable_actions_hash = match has_poll_actions {
+            true => self.get_poll_winner_actions_hash(proposal_id, proposal.ends_at),
+            false => proposal.actions_hash.clone(),
+        };
+
+        require!(executable_actions_hash == actions_hash, "actions have been corrupted");
         require!(!proposal.was_executed, "proposal has already been executed");
 
-        let has_member_approval = self.get_proposal_status(&proposal) == ProposalStatus::Succeeded;
+        let status = self.get_proposal_status(&proposal);
+        require!(status != ProposalStatus::Queued, "proposal is queued");
+        require!(status != ProposalStatus::Expired, "proposal has expired");
+        require!(status != ProposalStatus::Vetoed, "proposal has been vetoed");
+
+        let has_member_approval = status == ProposalStatus::Succeeded;
         let (allowed, permissions) = self.get_user_permissions_for_actions(&proposal.proposer, &actions, has_member_approval);
         require!(allowed, "no permission for action");
-        require!(proposal.permissions == permissions, "untruthful permissions announced");
+
+        // the announced permissions of a poll cover the actions of all options, so the winning option may only require some of them.
+        match has_poll_actions {
+            true => require!(permissions.iter().all(|perm| proposal.permissions.contains(&perm)), "untruthful permissions announced"),
+            false => require!(proposal.permissions == permissions, "untruthful permissions announced"),
+        }
 
         proposal.was_executed = true;
         self.proposals(proposal_id).set(&proposal);
@@ -405,6 +910,128 @@ pub trait GovernanceModule:
         self.cancel_proposal(proposal);
     }
 
+    /// Veto a proposal during its voting period or while it is queued for execution, so that it can not be executed.
+    /// Voters can withdraw their tokens right away.
+    /// Can only be called by members of the guardian role.
+    #[endpoint(veto)]
+    fn veto_endpoint(&self, proposal_id: u64) {
+        let caller = self.blockchain().get_caller();
+
+        require!(self.is_guardian(&caller), "caller must be guardian");
+        require!(!self.proposals(proposal_id).is_empty(), "proposal not found");
+
+        self.veto_proposal(&self.proposals(proposal_id).get());
+    }
+
+    /// Flag a proposal as spam, so that its deposit is slashed instead of refunded.
+    /// Only pending, active or defeated proposals can be flagged.
+    /// Can only be called by members of the guardian role.
+    #[endpoint(flagSpam)]
+    fn flag_spam_endpoint(&self, proposal_id: u64) {
+        require!(!self.proposals(proposal_id).is_empty(), "proposal not found");
+
+        let status = self.get_proposal_status(&self.proposals(proposal_id).get());
+
+        self.flag_proposal_spam(proposal_id, status);
+    }
+
+    /// Delegate the caller's voting weight to another address.
+    /// The delegate votes with the delegated weight on every proposal until the delegation is revoked.
+    /// Calling it again with another delegate moves the existing delegation.
+    /// Payment (optional):
+    ///     - token id must be equal to configured governance token id
+    ///     - tokens are deposited and locked until `undelegate` is called
+    /// If plugged, the caller's current plug weight is recorded and delegated as well.
+    #[payable("*")]
+    #[endpoint(delegate)]
+    fn delegate_endpoint(&self, delegate: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+
+        require!(caller != delegate, "can not delegate to self");
+        require!(
+            !payments.is_empty() || !self.delegated_tokens(&caller).is_empty() || self.is_plugged(),
+            "nothing to delegate"
+        );
+        self.require_payments_with_gov_token(&payments);
+        self.require_vote_tokens_allowed(&payments);
+
+        self.commit_delegation(&caller, &delegate, &payments);
+
+        if self.is_plugged() {
+            self.call_plug_vote_weight_async()
+                .with_callback(self.callbacks().delegate_async_callback(caller, delegate))
+                .call_and_exit();
+        }
+
+        self.emit_delegate_event(caller, delegate);
+    }
+
+    /// Record the delegator's plug weight via an asynchronous callback.
+    /// The callback result must return the original caller's vote weight.
+    #[callback]
+    fn delegate_async_callback(&self, original_caller: ManagedAddress, delegate: ManagedAddress, #[call_result] result: ManagedAsyncCallResult<BigUint>) {
+        match result {
+            ManagedAsyncCallResult::Ok(plug_weight) => {
+                self.delegated_plug_weight(&original_caller).set(&plug_weight);
+                self.emit_delegate_event(original_caller, delegate);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                sc_panic!("failed to retrieve caller vote weight");
+            }
+        };
+    }
+
+    /// Revoke the caller's delegation and return all delegated tokens.
+    /// Fails while a proposal that counted the delegated weight is still in its voting period.
+    #[endpoint(undelegate)]
+    fn undelegate_endpoint(&self) {
+        let caller = self.blockchain().get_caller();
+
+        self.revoke_delegation(&caller);
+    }
+
+    /// Lock governance tokens in escrow for the given duration in minutes.
+    /// The escrow grants voting power on every proposal without sending tokens, decaying as the unlock time approaches.
+    /// Payment:
+    ///     - token id must be equal to configured governance token id
+    ///     - only fungible tokens can be locked
+    #[payable("*")]
+    #[endpoint(lockEscrow)]
+    fn lock_escrow_endpoint(&self, lock_minutes: usize) {
+        let caller = self.blockchain().get_caller();
+        let payment = self.require_escrow_payment();
+
+        self.create_escrow_lock(&caller, payment, lock_minutes);
+    }
+
+    /// Extend the caller's escrow lock to end the given duration in minutes from now.
+    #[endpoint(extendEscrow)]
+    fn extend_escrow_endpoint(&self, lock_minutes: usize) {
+        let caller = self.blockchain().get_caller();
+
+        self.extend_escrow_lock(&caller, lock_minutes);
+    }
+
+    /// Add governance tokens to the caller's escrow lock without changing its unlock time.
+    #[payable("*")]
+    #[endpoint(increaseEscrow)]
+    fn increase_escrow_endpoint(&self) {
+        let caller = self.blockchain().get_caller();
+        let payment = self.require_escrow_payment();
+
+        self.increase_escrow_lock(&caller, payment);
+    }
+
+    /// Withdraw the caller's escrowed governance tokens once the lock has expired.
+    /// Fails while a proposal that counted the escrow's voting power is still in its voting period.
+    #[endpoint(withdrawEscrow)]
+    fn withdraw_escrow_endpoint(&self) {
+        let caller = self.blockchain().get_caller();
+
+        self.withdraw_escrow_lock(&caller);
+    }
+
     /// Withdraw locked governance tokens once the proposals voting period has ended.
     /// Used by members who voted FOR or AGAINST a proposal using ESDTs.
     #[endpoint(withdraw)]
@@ -479,6 +1106,10 @@ pub trait GovernanceModule:
     fn mint_endpoint(&self, token: TokenIdentifier, nonce: u64, amount: BigUint) {
         self.require_caller_self();
         self.send().esdt_local_mint(&token, nonce, &amount);
+
+        if self.is_gov_token(&token) {
+            self.gov_token_supply().update(|supply| *supply += &amount);
+        }
     }
 
     /// Burn tokens of any ESDT locally.
@@ -487,6 +1118,48 @@ pub trait GovernanceModule:
     fn burn_endpoint(&self, token: TokenIdentifier, nonce: u64, amount: BigUint) {
         self.require_caller_self();
         self.send().esdt_local_burn(&token, nonce, &amount);
+
+        if self.is_gov_token(&token) {
+            self.gov_token_supply().update(|supply| *supply = if *supply > amount { &*supply - &amount } else { BigUint::zero() });
+        }
+    }
+
+    #[view(getVoteTokens)]
+    fn get_vote_tokens_view(&self) -> MultiValueEncoded<MultiValue4<TokenIdentifier, u64, u8, bool>> {
+        let mut vote_tokens = MultiValueEncoded::new();
+
+        for (token_id, vote_token) in self.vote_tokens().iter() {
+            let lock_vote_tokens = self.lock_vote_tokens(&token_id).get();
+
+            vote_tokens.push((token_id, vote_token.multiplier, vote_token.decimals, lock_vote_tokens).into());
+        }
+
+        vote_tokens
+    }
+
+    #[view(getVoteNonceWeights)]
+    fn get_vote_nonce_weights_view(&self) -> MultiValueEncoded<MultiValue2<u64, BigUint>> {
+        let mut weights = MultiValueEncoded::new();
+
+        for (nonce, weight) in self.vote_nonce_weights().iter() {
+            weights.push((nonce, weight).into());
+        }
+
+        weights
+    }
+
+    /// Returns the per-unit vote weight of a governance token nonce. A weight of 0 means the nonce is excluded from voting.
+    #[view(getVoteNonceWeight)]
+    fn get_vote_nonce_weight_view(&self, nonce: u64) -> BigUint {
+        self.require_gov_token_set();
+
+        self.get_vote_nonce_weight(&self.gov_token_id().get(), nonce).unwrap_or_else(|| BigUint::from(1u64))
+    }
+
+    /// Returns the quorum a proposal created now would be evaluated against, taking the dynamic quorum into account.
+    #[view(getEffectiveQuorum)]
+    fn get_effective_quorum_view(&self) -> BigUint {
+        self.get_effective_quorum()
     }
 
     #[view(getProposal)]
@@ -516,18 +1189,28 @@ pub trait GovernanceModule:
         self.get_proposal_status(&self.proposals(proposal_id).get())
     }
 
+    /// Returns the seconds left until a proposal can be executed. Returns 0 if there is no execution delay left.
+    #[view(getProposalExecutionDelayRemaining)]
+    fn get_proposal_execution_delay_remaining_view(&self, proposal_id: u64) -> u64 {
+        require!(!self.proposals(proposal_id).is_empty(), "proposal not found");
+
+        let proposal = self.proposals(proposal_id).get();
+        let current_time = self.blockchain().get_block_timestamp();
+
+        proposal.executable_after.saturating_sub(current_time)
+    }
+
     #[view(getProposalVotes)]
-    fn get_proposal_votes_view(&self, proposal_id: u64) -> MultiValue2<BigUint, BigUint> {
+    fn get_proposal_votes_view(&self, proposal_id: u64) -> MultiValue3<BigUint, BigUint, BigUint> {
         let proposal = self.proposals(proposal_id).get();
 
-        (proposal.votes_for, proposal.votes_against).into()
+        (proposal.votes_for, proposal.votes_against, proposal.votes_abstain).into()
     }
 
     #[view(getProposalSigners)]
     fn get_proposal_signers_view(&self, proposal_id: u64) -> MultiValueEncoded<ManagedAddress> {
         let proposal = self.proposals(proposal_id).get();
-        let proposer_id = self.users().get_user_id(&proposal.proposer);
-        let proposer_roles = self.user_roles(proposer_id);
+        let proposer_roles = self.get_proposal_roles(&proposal);
         let mut signers = MultiValueEncoded::new();
 
         for role in proposer_roles.iter() {
@@ -544,14 +1227,13 @@ pub trait GovernanceModule:
     #[view(getProposalSignatureRoleCounts)]
     fn get_proposal_signature_role_counts_view(&self, proposal_id: u64) -> MultiValueEncoded<MultiValue2<ManagedBuffer, usize>> {
         let proposal = self.proposals(proposal_id).get();
-        let proposer_id = self.users().get_user_id(&proposal.proposer);
-        let proposer_roles = self.user_roles(proposer_id);
+        let proposer_roles = self.get_proposal_roles(&proposal);
         let mut signers = MultiValueEncoded::new();
 
         for role in proposer_roles.iter() {
             let signer_count = self.proposal_signers(proposal.id, &role).len();
             if signer_count > 0 {
-                signers.push((role, signer_count).into());
+                signers.push((role.clone_value(), signer_count).into());
             }
         }
         signers
@@ -561,15 +1243,19 @@ pub trait GovernanceModule:
     fn get_proposal_poll_results_view(&self, proposal_id: u64) -> MultiValueEncoded<BigUint> {
         let mut results = MultiValueEncoded::new();
 
-        for option_id in 1..=POLL_MAX_OPTIONS {
+        for option_id in 1..=self.get_poll_option_count(proposal_id) {
             results.push(self.proposal_poll(proposal_id, option_id).get());
         }
 
         results
     }
 
-    fn get_vote_weight_from_payments(&self, payments: &ManagedVec<EsdtTokenPayment<Self::Api>>) -> BigUint {
-        payments.into_iter().fold(BigUint::zero(), |carry, payment| carry + &payment.amount)
+    /// Returns the winning option of a proposal's poll by the poll's selection mode, or 0 if there is no winner yet or the leading options are tied.
+    #[view(getPollWinner)]
+    fn get_poll_winner_view(&self, proposal_id: u64) -> u8 {
+        require!(!self.proposals(proposal_id).is_empty(), "proposal not found");
+
+        self.get_poll_winner(proposal_id)
     }
 
     /// Processes received vote payment tokens.
@@ -593,7 +1279,7 @@ pub trait GovernanceModule:
                 self.guarded_vote_tokens(&payment.token_identifier, payment.token_nonce)
                     .update(|current| *current += &payment.amount);
             } else {
-                let inserted = self.proposal_nft_votes(proposal_id).insert(payment.token_nonce);
+                let inserted = self.get_proposal_nft_votes(proposal_id, &payment.token_identifier).insert(payment.token_nonce);
                 require!(inserted, ALREADY_VOTED_WITH_TOKEN);
                 returnables.push(payment);
             }
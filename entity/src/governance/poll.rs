@@ -0,0 +1,300 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc::api::KECCAK256_RESULT_LEN;
+
+use super::events;
+use crate::config::{self, POLL_MAX_OPTIONS, POLL_MAX_RANKED_BALLOTS};
+
+/// Controls how voters select poll options and how the winning option is determined.
+/// - Single: a ballot selects one option, the option with the most weight wins.
+/// - Approval: a ballot selects any number of options, each receiving the full weight. The option with the most weight wins.
+/// - Ranked: a ballot ranks options by preference. The winner is determined by instant runoff.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Debug, Clone, Copy)]
+pub enum PollMode {
+    Single,
+    Approval,
+    Ranked,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct Poll<M: ManagedTypeApi> {
+    pub mode: PollMode,
+    pub option_count: u8,
+    pub option_hashes: ManagedVec<M, ManagedBuffer<M>>,
+    pub option_actions_hashes: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct PollBallot<M: ManagedTypeApi> {
+    pub weight: BigUint<M>,
+    pub options: ManagedVec<M, u8>,
+}
+
+#[multiversx_sc::module]
+pub trait PollModule: config::ConfigModule + events::GovEventsModule {
+    fn require_valid_poll(&self, poll: &Poll<Self::Api>) {
+        require!(poll.option_count >= 2 && poll.option_count <= POLL_MAX_OPTIONS, "invalid poll option count");
+        require!(
+            poll.option_hashes.is_empty() || poll.option_hashes.len() == poll.option_count as usize,
+            "invalid poll option hashes"
+        );
+        require!(
+            poll.option_actions_hashes.is_empty() || poll.option_actions_hashes.len() == poll.option_count as usize,
+            "invalid poll option actions hashes"
+        );
+
+        for actions_hash in poll.option_actions_hashes.iter() {
+            require!(actions_hash.len() == KECCAK256_RESULT_LEN, "invalid actions hash");
+        }
+    }
+
+    /// Combines the actions hashes of all poll options into the proposal's actions hash, so the proposal is evaluated as one with actions.
+    /// Returns an empty hash if the options have no actions.
+    fn calculate_poll_actions_hash(&self, option_actions_hashes: &ManagedVec<ManagedBuffer>) -> ManagedBuffer {
+        if option_actions_hashes.is_empty() {
+            return ManagedBuffer::new();
+        }
+
+        let mut serialized = ManagedBuffer::new();
+
+        for actions_hash in option_actions_hashes.iter() {
+            serialized.append(&actions_hash);
+        }
+
+        self.crypto().keccak256(&serialized).as_managed_buffer().clone()
+    }
+
+    fn has_poll_actions(&self, proposal_id: u64) -> bool {
+        !self.polls(proposal_id).is_empty() && !self.polls(proposal_id).get().option_actions_hashes.is_empty()
+    }
+
+    /// Returns the actions hash of the winning option of a poll with actions per option.
+    /// Fails while voting is still ongoing or if there is no winner.
+    fn get_poll_winner_actions_hash(&self, proposal_id: u64, ends_at: u64) -> ManagedBuffer {
+        require!(self.blockchain().get_block_timestamp() >= ends_at, "poll voting has not ended");
+
+        let winner = self.get_poll_winner(proposal_id);
+        require!(winner != 0, "poll has no winner");
+
+        self.polls(proposal_id).get().option_actions_hashes.get(winner as usize - 1).clone_value()
+    }
+
+    /// Options of proposals without a declared poll are only bound by the max amount of poll options.
+    /// Declared polls only accept single options outside of ballots if they are single choice.
+    fn require_valid_poll_option(&self, proposal_id: u64, option_id: u8) {
+        if option_id == 0 {
+            return;
+        }
+
+        if self.polls(proposal_id).is_empty() {
+            require!(option_id <= POLL_MAX_OPTIONS, "invalid poll option");
+            return;
+        }
+
+        let poll = self.polls(proposal_id).get();
+
+        require!(poll.mode == PollMode::Single, "poll requires a ballot");
+        require!(option_id <= poll.option_count, "invalid poll option");
+    }
+
+    fn cast_poll_ballot(&self, proposal_id: u64, voter: &ManagedAddress, options: ManagedVec<u8>, weight: BigUint) {
+        require!(!self.polls(proposal_id).is_empty(), "proposal has no poll");
+        require!(!self.poll_ballots(proposal_id).contains_key(voter), "poll ballot already cast");
+        require!(weight > 0, "vote weight must be greater than 0");
+
+        let poll = self.polls(proposal_id).get();
+
+        require!(!options.is_empty(), "no poll option selected");
+        require!(poll.mode != PollMode::Single || options.len() == 1, "poll allows a single option only");
+
+        // ranked polls are tallied over all ballots in each instant runoff round on execution, so their amount is bounded.
+        let is_ranked_poll_full = poll.mode == PollMode::Ranked && self.poll_ballots(proposal_id).len() >= POLL_MAX_RANKED_BALLOTS;
+        require!(!is_ranked_poll_full, "max ranked poll ballots reached");
+
+        for (index, option_id) in options.iter().enumerate() {
+            require!(option_id > 0 && option_id <= poll.option_count, "invalid poll option");
+            require!(!options.iter().skip(index + 1).any(|other| other == option_id), "duplicate poll option");
+        }
+
+        self.add_poll_ballot_weight(proposal_id, &poll, &options, &weight);
+        self.poll_ballot_event(voter.clone(), proposal_id, weight.clone(), options.clone());
+        self.poll_ballots(proposal_id).insert(voter.clone(), PollBallot { weight, options });
+    }
+
+    fn remove_poll_ballot(&self, proposal_id: u64, voter: &ManagedAddress) {
+        if let Some(ballot) = self.poll_ballots(proposal_id).remove(voter) {
+            let poll = self.polls(proposal_id).get();
+
+            for option_id in self.get_tallied_ballot_options(&poll, &ballot.options).iter() {
+                self.proposal_poll(proposal_id, option_id).update(|current| *current -= &ballot.weight);
+            }
+        }
+    }
+
+    /// Sets the weight of a cast ballot and moves the tallied weight of its options along. A ballot without weight is removed.
+    fn update_poll_ballot_weight(&self, proposal_id: u64, voter: &ManagedAddress, weight: BigUint) {
+        let ballot = match self.poll_ballots(proposal_id).get(voter) {
+            Some(ballot) => ballot,
+            None => return,
+        };
+
+        if ballot.weight == weight {
+            return;
+        }
+
+        if weight == 0 {
+            self.remove_poll_ballot(proposal_id, voter);
+            return;
+        }
+
+        let poll = self.polls(proposal_id).get();
+
+        for option_id in self.get_tallied_ballot_options(&poll, &ballot.options).iter() {
+            self.proposal_poll(proposal_id, option_id)
+                .update(|current| *current = &*current - &ballot.weight + &weight);
+        }
+
+        self.poll_ballots(proposal_id).insert(voter.clone(), PollBallot { weight, options: ballot.options });
+    }
+
+    fn add_poll_ballot_weight(&self, proposal_id: u64, poll: &Poll<Self::Api>, options: &ManagedVec<u8>, weight: &BigUint) {
+        for option_id in self.get_tallied_ballot_options(poll, options).iter() {
+            self.proposal_poll(proposal_id, option_id).update(|current| *current += weight);
+        }
+    }
+
+    /// Returns the options a ballot adds its weight to in the poll results. Ranked ballots only count toward their first preference.
+    fn get_tallied_ballot_options(&self, poll: &Poll<Self::Api>, options: &ManagedVec<u8>) -> ManagedVec<u8> {
+        match poll.mode {
+            PollMode::Ranked => options.slice(0, 1).unwrap_or_default(),
+            _ => options.clone(),
+        }
+    }
+
+    fn get_poll_option_count(&self, proposal_id: u64) -> u8 {
+        match self.polls(proposal_id).is_empty() {
+            true => POLL_MAX_OPTIONS,
+            false => self.polls(proposal_id).get().option_count,
+        }
+    }
+
+    /// Returns the winning option of a poll, or 0 if there are no votes or the leading options are tied.
+    fn get_poll_winner(&self, proposal_id: u64) -> u8 {
+        if !self.polls(proposal_id).is_empty() && self.polls(proposal_id).get().mode == PollMode::Ranked {
+            return self.get_ranked_poll_winner(proposal_id);
+        }
+
+        let mut results = ManagedVec::<Self::Api, BigUint>::new();
+
+        for option_id in 1..=self.get_poll_option_count(proposal_id) {
+            results.push(self.proposal_poll(proposal_id, option_id).get());
+        }
+
+        self.get_leading_option(&results, &ManagedVec::new())
+    }
+
+    /// Determines the winner of a ranked poll by instant runoff.
+    /// Each round, every ballot counts toward its most preferred option that has not been eliminated.
+    /// An option with a majority of the counted weight wins, otherwise the option with the least weight is eliminated.
+    fn get_ranked_poll_winner(&self, proposal_id: u64) -> u8 {
+        let option_count = self.polls(proposal_id).get().option_count;
+        let mut eliminated = ManagedVec::<Self::Api, u8>::new();
+        let mut ballots = ManagedVec::<Self::Api, PollBallot<Self::Api>>::new();
+
+        for (_, ballot) in self.poll_ballots(proposal_id).iter() {
+            ballots.push(ballot);
+        }
+
+        loop {
+            let mut results = ManagedVec::<Self::Api, BigUint>::new();
+            let mut total = BigUint::zero();
+
+            for _ in 0..option_count {
+                results.push(BigUint::zero());
+            }
+
+            for ballot in ballots.iter() {
+                if let Some(option_id) = ballot.options.iter().find(|option_id| !eliminated.contains(option_id)) {
+                    let index = option_id as usize - 1;
+                    let _ = results.set(index, &(results.get(index).clone_value() + &ballot.weight));
+                    total += &ballot.weight;
+                }
+            }
+
+            if total == 0 {
+                return 0;
+            }
+
+            let leader = self.get_leading_option(&results, &eliminated);
+
+            if leader != 0 && results.get(leader as usize - 1).clone_value() * 2u64 > total {
+                return leader;
+            }
+
+            let remaining = option_count as usize - eliminated.len();
+
+            if remaining <= 2 {
+                return leader;
+            }
+
+            eliminated.push(self.get_trailing_option(&results, &eliminated));
+        }
+    }
+
+    /// Returns the option with the most weight, or 0 if there is none or the leading options are tied.
+    fn get_leading_option(&self, results: &ManagedVec<BigUint>, excluded: &ManagedVec<u8>) -> u8 {
+        let mut leader = 0u8;
+        let mut leader_weight = BigUint::zero();
+        let mut is_tied = false;
+
+        for (index, weight) in results.iter().enumerate() {
+            let option_id = index as u8 + 1;
+
+            if excluded.contains(&option_id) || *weight == 0 {
+                continue;
+            }
+
+            if *weight > leader_weight {
+                leader = option_id;
+                leader_weight = weight.clone_value();
+                is_tied = false;
+            } else if *weight == leader_weight {
+                is_tied = true;
+            }
+        }
+
+        match is_tied {
+            true => 0,
+            false => leader,
+        }
+    }
+
+    /// Returns the remaining option with the least weight. Ties eliminate the highest option id.
+    fn get_trailing_option(&self, results: &ManagedVec<BigUint>, excluded: &ManagedVec<u8>) -> u8 {
+        let mut trailing = 0u8;
+        let mut trailing_weight = BigUint::zero();
+
+        for (index, weight) in results.iter().enumerate() {
+            let option_id = index as u8 + 1;
+
+            if excluded.contains(&option_id) {
+                continue;
+            }
+
+            if trailing == 0 || *weight <= trailing_weight {
+                trailing = option_id;
+                trailing_weight = weight.clone_value();
+            }
+        }
+
+        trailing
+    }
+
+    #[view(getPoll)]
+    #[storage_mapper("poll:polls")]
+    fn polls(&self, proposal_id: u64) -> SingleValueMapper<Poll<Self::Api>>;
+
+    #[storage_mapper("poll:ballots")]
+    fn poll_ballots(&self, proposal_id: u64) -> MapMapper<ManagedAddress, PollBallot<Self::Api>>;
+}
@@ -3,15 +3,19 @@ multiversx_sc::derive_imports!();
 
 use multiversx_sc::api::KECCAK256_RESULT_LEN;
 
+use super::delegation;
+use super::deposit;
+use super::escrow;
 use super::events;
-use crate::config;
+use super::poll::{self, Poll};
+use crate::config::{self, DYNAMIC_QUORUM_SAMPLE_SIZE_MAX};
 use crate::permission;
 use crate::permission::PermissionDetails;
 use crate::permission::{Policy, PolicyMethod, ROLE_BUILTIN_LEADER};
 use crate::plug;
 use core::convert::TryFrom;
 
-#[derive(TopEncode, TopDecode, TypeAbi)]
+#[derive(TopEncode, TypeAbi)]
 pub struct Proposal<M: ManagedTypeApi> {
     pub id: u64,
     pub proposer: ManagedAddress<M>,
@@ -23,6 +27,84 @@ pub struct Proposal<M: ManagedTypeApi> {
     pub votes_for: BigUint<M>,
     pub votes_against: BigUint<M>,
     pub permissions: ManagedVec<M, ManagedBuffer<M>>,
+    pub votes_abstain: BigUint<M>,
+    pub executable_after: u64,
+    pub snapshot: Option<ProposalSnapshot<M>>,
+    pub is_emergency: bool,
+}
+
+// fields appended after `permissions` are optional to keep decoding proposals stored by earlier versions.
+impl<M: ManagedTypeApi> TopDecode for Proposal<M> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> core::result::Result<Self, H::HandledErr>
+    where
+        I: codec::TopDecodeInput,
+        H: codec::DecodeErrorHandler,
+    {
+        let mut input = input.into_nested_buffer();
+
+        let proposal = Proposal {
+            id: u64::dep_decode_or_handle_err(&mut input, h)?,
+            proposer: ManagedAddress::dep_decode_or_handle_err(&mut input, h)?,
+            content_hash: ManagedBuffer::dep_decode_or_handle_err(&mut input, h)?,
+            actions_hash: ManagedBuffer::dep_decode_or_handle_err(&mut input, h)?,
+            starts_at: u64::dep_decode_or_handle_err(&mut input, h)?,
+            ends_at: u64::dep_decode_or_handle_err(&mut input, h)?,
+            was_executed: bool::dep_decode_or_handle_err(&mut input, h)?,
+            votes_for: BigUint::dep_decode_or_handle_err(&mut input, h)?,
+            votes_against: BigUint::dep_decode_or_handle_err(&mut input, h)?,
+            permissions: ManagedVec::dep_decode_or_handle_err(&mut input, h)?,
+            votes_abstain: decode_optional_field(&mut input, h)?,
+            executable_after: decode_optional_field(&mut input, h)?,
+            snapshot: decode_optional_field(&mut input, h)?,
+            is_emergency: decode_optional_field(&mut input, h)?,
+        };
+
+        if !codec::NestedDecodeInput::is_depleted(&input) {
+            return core::result::Result::Err(h.handle_error(codec::DecodeError::INPUT_TOO_LONG));
+        }
+
+        core::result::Result::Ok(proposal)
+    }
+}
+
+pub(crate) fn decode_optional_field<T, I, H>(input: &mut I, h: H) -> core::result::Result<T, H::HandledErr>
+where
+    T: NestedDecode + Default,
+    I: codec::NestedDecodeInput,
+    H: codec::DecodeErrorHandler,
+{
+    if input.is_depleted() {
+        return core::result::Result::Ok(T::default());
+    }
+
+    T::dep_decode_or_handle_err(input, h)
+}
+
+/// Governance parameters captured when a proposal is created, so later configuration changes do not alter its outcome.
+/// Proposals stored by earlier versions have no snapshot and are evaluated against the current configuration.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct ProposalSnapshot<M: ManagedTypeApi> {
+    pub quorum: BigUint<M>,
+    pub pass_threshold: u64,
+    pub quorum_mode: QuorumMode,
+    pub is_leaderless: bool,
+    pub roles: ManagedVec<M, RoleSnapshot<M>>,
+    pub policies: ManagedVec<M, PolicySnapshot<M>>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct RoleSnapshot<M: ManagedTypeApi> {
+    pub role: ManagedBuffer<M>,
+    pub member_amount: usize,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct PolicySnapshot<M: ManagedTypeApi> {
+    pub role: ManagedBuffer<M>,
+    pub permission: ManagedBuffer<M>,
+    pub method: PolicyMethod,
+    pub quorum: BigUint<M>,
+    pub pass_threshold: u64,
 }
 
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone)]
@@ -43,16 +125,54 @@ pub enum ProposalStatus {
     Succeeded,
     Executed,
     Canceled,
+    Queued,
+    Expired,
+    Vetoed,
 }
 
 #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug, Clone)]
 pub enum VoteType {
     For = 1,
     Against = 2,
+    Abstain = 3,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct VoteReceipt<M: ManagedTypeApi> {
+    pub vote_type: VoteType,
+    pub weight: BigUint<M>,
+    pub poll_option: u8,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug, Clone, Copy)]
+pub enum QuorumMode {
+    ForOnly,
+    ForAndAgainst,
+    All,
+}
+
+/// Controls how repeated votes of the same address on a proposal are handled.
+/// - Cumulative: every vote is added up, also across vote types.
+/// - Single: an address can only vote once per proposal.
+/// - Replace: a later vote replaces the address' ballot, its previous weight is moved to the new vote type and poll option.
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug, Clone, Copy)]
+pub enum VoteMode {
+    Cumulative,
+    Single,
+    Replace,
 }
 
 #[multiversx_sc::module]
-pub trait ProposalModule: config::ConfigModule + permission::PermissionModule + events::GovEventsModule + plug::PlugModule {
+pub trait ProposalModule:
+    config::ConfigModule
+    + permission::PermissionModule
+    + events::GovEventsModule
+    + plug::PlugModule
+    + delegation::DelegationModule
+    + deposit::DepositModule
+    + escrow::EscrowModule
+    + poll::PollModule
+{
     fn create_proposal(
         &self,
         proposer: ManagedAddress,
@@ -63,18 +183,28 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         option_id: u8,
         vote_weight: BigUint,
         permissions: ManagedVec<ManagedBuffer>,
+        is_emergency: bool,
+        poll: Option<Poll<Self::Api>>,
     ) -> Proposal<Self::Api> {
         let proposal_id = self.next_proposal_id().get();
 
-        self.require_proposed_via_trusted_host(&proposer, &trusted_host_id, &content_hash, content_sig, &actions_hash, &permissions);
+        self.require_proposed_via_trusted_host(&proposer, &trusted_host_id, &content_hash, content_sig, &actions_hash, &permissions, &poll);
         require!(!self.known_trusted_host_proposal_ids().contains(&trusted_host_id), "proposal already registered");
 
+        if let Some(poll) = &poll {
+            self.require_valid_poll(poll);
+        }
+
         let (allowed, policies) = self.can_propose(&proposer, &actions_hash, &permissions);
         require!(allowed, "action not allowed for user");
 
         let proposer_id = self.users().get_user_id(&proposer);
         let proposer_roles = self.user_roles(proposer_id);
 
+        self.require_proposer_within_rate_limits(&proposer, &proposer_roles);
+
+        let vote_weight = vote_weight + self.get_delegation_vote_weight(&proposer, proposal_id) + self.get_escrow_vote_weight(&proposer, proposal_id);
+
         if proposer_roles.is_empty() || self.has_token_weighted_policy(&policies) {
             require!(vote_weight >= self.min_propose_weight().get(), "insufficient vote weight");
         }
@@ -83,17 +213,54 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             require!(actions_hash.len() == KECCAK256_RESULT_LEN, "invalid actions hash");
         }
 
-        let voting_period_minutes = policies
-            .iter()
-            .map(|p| p.voting_period_minutes)
-            .max()
-            .unwrap_or_else(|| self.voting_period_in_minutes().get());
+        let vote_weight = self.commit_effective_vote_weight(&proposer, proposal_id, vote_weight);
+
+        if let Some(poll) = poll {
+            self.polls(proposal_id).set(poll);
+        }
+
+        let sanitized_permissions: ManagedVec<ManagedBuffer> = permissions.into_iter().filter(|perm| !perm.is_empty()).collect();
+
+        if is_emergency {
+            self.require_valid_emergency_proposal(&sanitized_permissions);
+        }
+
+        let voting_period_minutes = match is_emergency {
+            true => self.emergency_config().get().voting_period_minutes,
+            false => policies
+                .iter()
+                .map(|p| p.voting_period_minutes)
+                .max()
+                .unwrap_or_else(|| self.voting_period_in_minutes().get()),
+        };
 
-        let starts_at = self.blockchain().get_block_timestamp();
+        let execution_delay_minutes = match is_emergency {
+            true => policies
+                .iter()
+                .map(|p| p.execution_delay_minutes)
+                .fold(self.emergency_config().get().execution_delay_minutes, |carry, delay| carry.max(delay)),
+            false => policies
+                .iter()
+                .map(|p| p.execution_delay_minutes)
+                .fold(self.execution_delay_in_minutes().get(), |carry, delay| carry.max(delay)),
+        };
+
+        // emergency proposals can be voted on right away.
+        let voting_delay_minutes = if is_emergency { 0 } else { self.voting_delay_in_minutes().get() };
+
+        let starts_at = self.blockchain().get_block_timestamp() + voting_delay_minutes as u64 * 60;
         let ends_at = starts_at + voting_period_minutes as u64 * 60;
-        let sanitized_permissions = permissions.into_iter().filter(|perm| !perm.is_empty()).collect();
+        let executable_after = if execution_delay_minutes > 0 { ends_at + execution_delay_minutes as u64 * 60 } else { 0 };
 
-        let proposal = Proposal {
+        self.record_proposal_turnouts();
+
+        let mut snapshot = self.take_proposal_snapshot(&proposer, &sanitized_permissions);
+
+        if is_emergency {
+            self.apply_emergency_snapshot(&mut snapshot);
+        }
+
+        let mut proposal = Proposal {
             id: proposal_id,
             proposer: proposer.clone(),
             content_hash,
@@ -104,25 +271,115 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             votes_for: vote_weight.clone(),
             votes_against: BigUint::zero(),
             permissions: sanitized_permissions,
+            votes_abstain: BigUint::zero(),
+            executable_after,
+            snapshot: Some(snapshot),
+            is_emergency,
         };
 
         if !proposer_roles.is_empty() {
             self.sign_for_all_roles(&proposer, &proposal);
         }
 
+        if !proposer_roles.is_empty() || is_emergency {
+            self.queue_if_succeeded_early(&mut proposal);
+        }
+
+        self.commit_delegation_votes(&proposer, &proposal);
+        self.commit_escrow_votes(&proposer, &proposal);
+
         self.proposals(proposal_id).set(&proposal);
         self.next_proposal_id().set(proposal_id + 1);
         self.cast_poll_vote(proposal.id.clone(), option_id, vote_weight.clone());
+        self.record_vote_receipt(&proposer, proposal.id, VoteType::For, &vote_weight, option_id);
         self.known_trusted_host_proposal_ids().insert(trusted_host_id);
+        self.track_proposer_proposal(&proposer, proposal.id);
+        self.track_proposal_turnout(proposal.id);
         self.emit_propose_event(proposer, &proposal, vote_weight, option_id);
 
         proposal
     }
 
+    /// Emergency proposals must be enabled and may only invoke the permissions allowed for emergencies.
+    fn require_valid_emergency_proposal(&self, permissions: &ManagedVec<ManagedBuffer>) {
+        require!(!self.emergency_config().is_empty(), "emergency proposals are disabled");
+        require!(!permissions.is_empty(), "emergency proposal requires permissions");
+
+        for permission in permissions.iter() {
+            require!(self.emergency_permissions().contains(&permission), "permission not allowed in emergency");
+        }
+    }
+
+    /// Raises the quorum and pass threshold of the snapshot to the emergency ones or those of its token weighted policies, whichever are higher.
+    fn apply_emergency_snapshot(&self, snapshot: &mut ProposalSnapshot<Self::Api>) {
+        let emergency_config = self.emergency_config().get();
+
+        if emergency_config.quorum > snapshot.quorum {
+            snapshot.quorum = emergency_config.quorum;
+        }
+
+        snapshot.pass_threshold = snapshot.pass_threshold.max(emergency_config.pass_threshold);
+
+        for policy in snapshot.policies.iter() {
+            if policy.method != PolicyMethod::Weight {
+                continue;
+            }
+
+            if policy.quorum > snapshot.quorum {
+                snapshot.quorum = policy.quorum.clone();
+            }
+
+            snapshot.pass_threshold = snapshot.pass_threshold.max(policy.pass_threshold);
+        }
+    }
+
+    /// Enforces the max amount of pending or active proposals per proposer and the cooldown between proposals.
+    /// Members of an exempt role are not rate limited.
+    fn require_proposer_within_rate_limits(&self, proposer: &ManagedAddress, proposer_roles: &UnorderedSetMapper<ManagedBuffer>) {
+        let is_exempt = self.proposal_rate_limit_exempt_roles().iter().any(|role| proposer_roles.contains(&role));
+
+        if is_exempt {
+            return;
+        }
+
+        let cooldown_seconds = self.proposal_cooldown_in_minutes().get() as u64 * 60;
+        let last_proposed_at = self.proposer_last_proposed_at(proposer).get();
+
+        if cooldown_seconds > 0 && !self.proposer_proposals(proposer).is_empty() {
+            require!(self.blockchain().get_block_timestamp() >= last_proposed_at + cooldown_seconds, "proposal cooldown active");
+        }
+
+        let max_active_proposals = self.max_active_proposals().get();
+
+        if max_active_proposals > 0 {
+            self.prune_proposer_active_proposals(proposer);
+            require!(self.proposer_active_proposals(proposer).len() < max_active_proposals, "too many active proposals");
+        }
+    }
+
+    fn prune_proposer_active_proposals(&self, proposer: &ManagedAddress) {
+        let mut active_proposals = self.proposer_active_proposals(proposer);
+        let proposal_ids = active_proposals.iter().collect::<ManagedVec<u64>>();
+
+        for proposal_id in proposal_ids.iter() {
+            let status = self.get_proposal_status(&self.proposals(proposal_id).get());
+
+            if status != ProposalStatus::Active && status != ProposalStatus::Pending {
+                active_proposals.swap_remove(&proposal_id);
+            }
+        }
+    }
+
+    fn track_proposer_proposal(&self, proposer: &ManagedAddress, proposal_id: u64) {
+        self.proposer_proposals(proposer).push(&proposal_id);
+        self.proposer_active_proposals(proposer).insert(proposal_id);
+        self.proposer_last_proposed_at(proposer).set(self.blockchain().get_block_timestamp());
+    }
+
     fn cancel_proposal(&self, mut proposal: Proposal<Self::Api>) {
         let status = self.get_proposal_status(&proposal);
 
-        require!(status == ProposalStatus::Active, "proposal is not active");
+        require!(status == ProposalStatus::Active || status == ProposalStatus::Pending, "proposal is not active");
 
         proposal.ends_at = 0;
         self.proposals(proposal.id).set(&proposal);
@@ -130,18 +387,126 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         self.emit_cancel_event(&proposal);
     }
 
+    /// Vetoes a proposal during its voting period or before it has been executed, e.g. during its execution delay.
+    /// Voters can withdraw their tokens right away.
+    /// A proposal can be vetoed during its voting period and, once succeeded, while it is queued for execution.
+    fn veto_proposal(&self, proposal: &Proposal<Self::Api>) {
+        let status = self.get_proposal_status(proposal);
+        let is_voting = self.blockchain().get_block_timestamp() < proposal.ends_at;
+        let can_veto = match status {
+            ProposalStatus::Pending | ProposalStatus::Active | ProposalStatus::Queued => true,
+            ProposalStatus::Succeeded => is_voting,
+            _ => false,
+        };
+
+        require!(can_veto, "proposal can not be vetoed");
+
+        self.vetoed_proposals().insert(proposal.id);
+        self.emit_veto_event(proposal);
+    }
+
     fn get_proposal_status(&self, proposal: &Proposal<Self::Api>) -> ProposalStatus {
+        let status = self.get_proposal_voting_status(proposal);
+
+        // proposals without actions have nothing to execute, so they are neither queued nor expire.
+        let has_actions = !proposal.actions_hash.is_empty() || !proposal.permissions.is_empty();
+
+        if status != ProposalStatus::Succeeded || !has_actions {
+            return status;
+        }
+
+        let current_time = self.blockchain().get_block_timestamp();
+
+        if current_time < proposal.executable_after {
+            return ProposalStatus::Queued;
+        }
+
+        let expires_at = self.get_proposal_expires_at(proposal);
+
+        if expires_at != 0 && current_time >= expires_at {
+            return ProposalStatus::Expired;
+        }
+
+        status
+    }
+
+    /// Starts the execution delay of a proposal that succeeded before its voting period has ended, e.g. via signer majority.
+    /// The delay is derived from the originally scheduled executable after timestamp.
+    /// Signatures collected while pending start the delay once the voting period starts.
+    fn queue_if_succeeded_early(&self, proposal: &mut Proposal<Self::Api>) {
+        let current_time = self.blockchain().get_block_timestamp();
+
+        if proposal.executable_after == 0 || current_time >= proposal.ends_at {
+            return;
+        }
+
+        if !self.has_succeeded_early(proposal) {
+            return;
+        }
+
+        let execution_delay = proposal.executable_after - proposal.ends_at;
+        let succeeded_at = current_time.max(proposal.starts_at);
+        proposal.executable_after = proposal.executable_after.min(succeeded_at + execution_delay);
+    }
+
+    /// A proposal with actions succeeds before its voting period has ended if it has a signer majority and no token weighted policy.
+    /// Polls with actions per option never succeed early, as the winning option is only final once voting has ended.
+    /// Emergency proposals succeed as soon as they reach the emergency thresholds and meet the signer requirements of their policies.
+    fn has_succeeded_early(&self, proposal: &Proposal<Self::Api>) -> bool {
+        if proposal.is_emergency {
+            return self.has_emergency_proposal_passed(proposal);
+        }
+
+        let has_actions = !proposal.actions_hash.is_empty() || !proposal.permissions.is_empty();
+
+        if !has_actions || self.has_poll_actions(proposal.id) {
+            return false;
+        }
+
+        let (_, meets_policy_requirements, has_weighted_policy) = self.get_policy_requirements(proposal, self.is_proposal_leaderless(proposal));
+
+        meets_policy_requirements && !has_weighted_policy
+    }
+
+    /// Evaluates the outcome of the voting without applying the execution delay.
+    fn get_proposal_voting_status(&self, proposal: &Proposal<Self::Api>) -> ProposalStatus {
+        self.evaluate_proposal_voting_status(proposal, self.is_proposal_active(proposal))
+    }
+
+    /// Evaluates the outcome the voting would have if the voting period ended right now.
+    fn get_proposal_outcome(&self, proposal: &Proposal<Self::Api>) -> ProposalStatus {
+        self.evaluate_proposal_voting_status(proposal, false)
+    }
+
+    fn evaluate_proposal_voting_status(&self, proposal: &Proposal<Self::Api>, is_active: bool) -> ProposalStatus {
         if proposal.ends_at == 0 {
             return ProposalStatus::Canceled;
         }
 
+        if self.vetoed_proposals().contains(&proposal.id) {
+            return ProposalStatus::Vetoed;
+        }
+
         if proposal.was_executed {
             return ProposalStatus::Executed;
         }
 
+        if self.blockchain().get_block_timestamp() < proposal.starts_at {
+            return ProposalStatus::Pending;
+        }
+
+        // emergency proposals succeed as soon as the emergency thresholds and the signer requirements of their policies are met.
+        if proposal.is_emergency {
+            return match self.has_emergency_proposal_passed(proposal) {
+                true => ProposalStatus::Succeeded,
+                false if is_active => ProposalStatus::Active,
+                false => ProposalStatus::Defeated,
+            };
+        }
+
         let has_gov_token = !self.gov_token_id().is_empty();
         let has_actions = !proposal.actions_hash.is_empty() || !proposal.permissions.is_empty();
-        let is_leaderless = self.is_leaderless();
+        let is_leaderless = self.is_proposal_leaderless(proposal);
 
         let (has_policies, meets_policy_requirements, has_weighted_policy) = if has_actions {
             self.get_policy_requirements(&proposal, is_leaderless)
@@ -150,11 +515,11 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         };
 
         // early succeed if signer majority & no token weighted policy
-        if meets_policy_requirements && !has_weighted_policy {
+        if meets_policy_requirements && !has_weighted_policy && !self.has_poll_actions(proposal.id) {
             return ProposalStatus::Succeeded;
         }
 
-        if self.is_proposal_active(&proposal) {
+        if is_active {
             return ProposalStatus::Active;
         }
 
@@ -162,7 +527,7 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         let is_leaderless_weighted = is_leaderless && !has_policies;
 
         if is_weight_based && (is_leaderless_weighted || !has_actions) {
-            return match self.has_sufficient_votes(&proposal, &self.quorum().get()) {
+            return match self.has_sufficient_votes(&proposal, &self.get_proposal_quorum(proposal), self.get_proposal_pass_threshold(proposal)) {
                 true => ProposalStatus::Succeeded,
                 false => ProposalStatus::Defeated,
             };
@@ -175,9 +540,20 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         ProposalStatus::Defeated
     }
 
+    /// The signer requirements of the policies still apply to emergency proposals. Token weighted ones are covered by the snapshot thresholds.
+    fn has_emergency_proposal_passed(&self, proposal: &Proposal<Self::Api>) -> bool {
+        if !self.has_proposal_sufficient_votes(proposal) {
+            return false;
+        }
+
+        let (has_policies, meets_policy_requirements, _) = self.get_policy_requirements(proposal, self.is_proposal_leaderless(proposal));
+
+        !has_policies || meets_policy_requirements
+    }
+
     fn get_policy_requirements(&self, proposal: &Proposal<Self::Api>, is_leaderless: bool) -> (bool, bool, bool) {
         let proposer_id = self.users().get_user_id(&proposal.proposer);
-        let proposer_roles = self.user_roles(proposer_id);
+        let proposer_roles = self.get_proposal_roles(proposal);
 
         // Signer majority is required if permissions are not explicitly set and DAO is not leaderless.
         if !is_leaderless && proposal.permissions.is_empty() {
@@ -203,17 +579,22 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             let is_satisfied_perm = proposer_roles
                 .iter()
                 .map(|role| {
-                    if let Some(policy) = self.policies(&role).get(&permission) {
+                    if let Some(policy) = self.get_proposal_policy(proposal, &role, &permission) {
                         has_policies = true;
 
                         match policy.method {
                             PolicyMethod::Weight => {
                                 has_weighted_policy = true;
-                                self.has_sufficient_votes(&proposal, &policy.quorum)
+                                self.has_sufficient_votes(&proposal, &policy.quorum, policy.pass_threshold)
                             }
                             PolicyMethod::One => self.proposal_signers(proposal.id, &role).contains(&proposer_id),
-                            PolicyMethod::All => self.proposal_signers(proposal.id, &role).len() >= self.roles_member_amount(&role).get(),
+                            PolicyMethod::All => self.proposal_signers(proposal.id, &role).len() >= self.get_proposal_role_member_amount(proposal, &role),
                             PolicyMethod::Quorum => BigUint::from(self.proposal_signers(proposal.id, &role).len()) >= policy.quorum,
+                            PolicyMethod::Optimistic => {
+                                // objections can be raised until the end of the voting period, so it never succeeds early.
+                                has_weighted_policy = true;
+                                proposal.votes_against < policy.quorum
+                            }
                         }
                     } else {
                         // If no specific policy is set, fallback to checking for a signer majority.
@@ -231,8 +612,6 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
     }
 
     fn execute_actions(&self, actions: &ManagedVec<Action<Self::Api>>) {
-        let gov_token_id = self.gov_token_id().get();
-
         for action in actions.iter() {
             let mut call = self.send().contract_call::<()>(action.destination, action.endpoint).with_gas_limit(action.gas_limit);
 
@@ -241,13 +620,14 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             }
 
             if action.value > 0 {
+                self.require_egld_available(&action.value);
                 call.with_egld_transfer(action.value).transfer_execute();
                 break;
             }
 
             for payment in action.payments.iter() {
-                if payment.token_identifier == gov_token_id {
-                    self.require_gov_tokens_available(&payment.amount, payment.token_nonce);
+                if self.is_vote_token(&payment.token_identifier) || !self.guarded_vote_tokens(&payment.token_identifier, payment.token_nonce).is_empty() {
+                    self.require_vote_tokens_available(&payment.token_identifier, &payment.amount, payment.token_nonce);
                 }
             }
 
@@ -256,32 +636,251 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
     }
 
     fn vote(&self, voter: ManagedAddress, proposal_id: u64, vote_type: VoteType, weight: BigUint, option_id: u8) {
-        require!(weight > 0, "vote weight must be greater than 0");
         require!(!self.proposals(proposal_id).is_empty(), "proposal does not exist");
 
         let mut proposal = self.proposals(proposal_id).get();
-        let min_vote_weight = self.min_vote_weight().get();
+        let weight = weight + self.commit_delegation_votes(&voter, &proposal) + self.commit_escrow_votes(&voter, &proposal);
+        let replaces_ballot = self.vote_mode().get() == VoteMode::Replace && !self.vote_receipts(proposal_id, &voter).is_empty();
 
-        require!(weight >= min_vote_weight, "not enought vote weight");
-        require!(self.get_proposal_status(&proposal) == ProposalStatus::Active, "proposal is not active");
+        // a replacing ballot keeps the weight it has already been cast with, so no additional weight is required.
+        if !replaces_ballot {
+            require!(weight > 0, "vote weight must be greater than 0");
 
-        match vote_type {
-            VoteType::For => proposal.votes_for += &weight,
-            VoteType::Against => proposal.votes_against += &weight,
+            let min_vote_weight = self.min_vote_weight().get();
+
+            require!(weight >= min_vote_weight, "not enought vote weight");
         }
 
+        let status = self.get_proposal_status(&proposal);
+        require!(status != ProposalStatus::Pending, "voting has not started");
+        require!(status == ProposalStatus::Active, "proposal is not active");
+
+        self.require_vote_mode_allows_ballot(&voter, proposal_id);
+
+        let previous_outcome = self.get_proposal_outcome(&proposal);
+
+        if replaces_ballot {
+            self.move_vote_receipts(&voter, &mut proposal, &vote_type, Some(option_id));
+        }
+
+        let weight = self.commit_effective_vote_weight(&voter, proposal_id, weight);
+
+        self.add_vote_weight(&mut proposal, &vote_type, &weight);
+        self.extend_voting_if_outcome_flipped(&mut proposal, previous_outcome);
+        self.queue_if_succeeded_early(&mut proposal);
         self.proposals(proposal_id).set(&proposal);
         self.cast_poll_vote(proposal_id, option_id, weight.clone());
+        self.record_vote_receipt(&voter, proposal_id, vote_type.clone(), &weight, option_id);
+
+        if self.poll_ballots(proposal_id).contains_key(&voter) {
+            self.update_poll_ballot_weight(proposal_id, &voter, self.get_poll_ballot_weight(&voter, proposal_id));
+        }
+
         self.emit_vote_event(voter, &proposal, vote_type, weight, option_id);
     }
 
-    fn sign(&self, proposal_id: u64, option_id: u8) {
-        let proposal = self.proposals(proposal_id).get();
+    /// Casts a poll ballot with the voter's FOR weight that is not bound to a single poll option.
+    /// Weight sent along is voted FOR the proposal first. A ballot can be cast once per proposal.
+    fn vote_poll(&self, voter: ManagedAddress, proposal_id: u64, weight: BigUint, options: ManagedVec<u8>) {
+        require!(!self.proposals(proposal_id).is_empty(), "proposal does not exist");
+
+        if weight > 0 || self.vote_receipts(proposal_id, &voter).is_empty() {
+            self.vote(voter.clone(), proposal_id, VoteType::For, weight, 0);
+        } else {
+            let proposal = self.proposals(proposal_id).get();
+            require!(self.get_proposal_status(&proposal) == ProposalStatus::Active, "proposal is not active");
+        }
+
+        let ballot_weight = self.get_poll_ballot_weight(&voter, proposal_id);
+
+        self.cast_poll_ballot(proposal_id, &voter, options, ballot_weight);
+    }
+
+    /// Returns the voter's FOR weight on a proposal that is not bound to a single poll option, which their poll ballot is cast with.
+    fn get_poll_ballot_weight(&self, voter: &ManagedAddress, proposal_id: u64) -> BigUint {
+        self.vote_receipts(proposal_id, voter)
+            .iter()
+            .filter(|receipt| receipt.vote_type == VoteType::For && receipt.poll_option == 0)
+            .fold(BigUint::zero(), |carry, receipt| carry + receipt.weight)
+    }
+
+    /// Moves all of the voter's weight on a proposal to the given vote type.
+    fn change_vote(&self, voter: ManagedAddress, proposal_id: u64, vote_type: VoteType) {
+        require!(!self.proposals(proposal_id).is_empty(), "proposal does not exist");
+
+        let mut proposal = self.proposals(proposal_id).get();
+        require!(self.get_proposal_status(&proposal) == ProposalStatus::Active, "proposal is not active");
+
+        require!(!self.vote_receipts(proposal_id, &voter).is_empty(), "no vote to change");
+
+        let previous_outcome = self.get_proposal_outcome(&proposal);
+        let total_weight = self.move_vote_receipts(&voter, &mut proposal, &vote_type, None);
+
+        if vote_type != VoteType::For {
+            self.remove_poll_ballot(proposal_id, &voter);
+        }
+
+        self.extend_voting_if_outcome_flipped(&mut proposal, previous_outcome);
+        self.queue_if_succeeded_early(&mut proposal);
+        self.proposals(proposal_id).set(&proposal);
+        self.change_vote_event(voter, proposal_id, vote_type, total_weight);
+    }
+
+    /// Removes all of the voter's weight from a proposal and returns the deposited vote tokens.
+    /// Weight from a plug, delegations, escrow or NFTs returned on vote can not be cast again on the same proposal.
+    fn revoke_vote(&self, voter: ManagedAddress, proposal_id: u64) {
+        require!(!self.proposals(proposal_id).is_empty(), "proposal does not exist");
+
+        let mut proposal = self.proposals(proposal_id).get();
         require!(self.get_proposal_status(&proposal) == ProposalStatus::Active, "proposal is not active");
 
+        let mut receipts_mapper = self.vote_receipts(proposal_id, &voter);
+        require!(!receipts_mapper.is_empty(), "no vote to revoke");
+
+        let previous_outcome = self.get_proposal_outcome(&proposal);
+        let mut total_weight = BigUint::zero();
+
+        for receipt in receipts_mapper.iter() {
+            self.remove_vote_weight(&mut proposal, &receipt.vote_type, &receipt.weight);
+
+            if receipt.poll_option != 0 {
+                self.proposal_poll(proposal_id, receipt.poll_option)
+                    .update(|current| *current -= &receipt.weight);
+            }
+
+            total_weight += &receipt.weight;
+        }
+
+        receipts_mapper.clear();
+        self.quadratic_vote_tokens(proposal_id, &voter).clear();
+        self.remove_poll_ballot(proposal_id, &voter);
+        self.extend_voting_if_outcome_flipped(&mut proposal, previous_outcome);
+        self.proposals(proposal_id).set(&proposal);
+
+        let mut returnables: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
+
+        for vote in self.withdrawable_votes(proposal_id, &voter).iter() {
+            self.guarded_vote_tokens(&vote.token_identifier, vote.token_nonce)
+                .update(|current| *current -= &vote.amount);
+
+            returnables.push(vote);
+        }
+
+        self.withdrawable_votes(proposal_id, &voter).clear();
+
+        // the proposer stays withdrawable until the proposal deposit has been settled after voting.
+        let has_unsettled_deposit = voter == proposal.proposer && !self.proposal_deposits(proposal_id).is_empty();
+
+        if !has_unsettled_deposit {
+            self.withdrawable_proposal_ids(&voter).swap_remove(&proposal_id);
+            self.withdrawable_voters(proposal_id).swap_remove(&self.users().get_user_id(&voter));
+        }
+
+        self.revoke_vote_event(voter.clone(), proposal_id, total_weight);
+
+        if !returnables.is_empty() {
+            self.send().direct_multi(&voter, &returnables);
+        }
+    }
+
+    /// Moves the weight of all existing vote receipts of the voter to the given vote type and, if provided, poll option.
+    /// Returns the total weight of the receipts.
+    fn move_vote_receipts(&self, voter: &ManagedAddress, proposal: &mut Proposal<Self::Api>, vote_type: &VoteType, opt_option_id: Option<u8>) -> BigUint {
+        let receipts_mapper = self.vote_receipts(proposal.id, voter);
+        let mut total_weight = BigUint::zero();
+
+        for index in 1..=receipts_mapper.len() {
+            let mut receipt = receipts_mapper.get(index);
+            let option_id = opt_option_id.unwrap_or(receipt.poll_option);
+            total_weight += &receipt.weight;
+
+            if &receipt.vote_type == vote_type && receipt.poll_option == option_id {
+                continue;
+            }
+
+            self.remove_vote_weight(proposal, &receipt.vote_type, &receipt.weight);
+            self.add_vote_weight(proposal, vote_type, &receipt.weight);
+
+            if receipt.poll_option != option_id {
+                if receipt.poll_option != 0 {
+                    self.proposal_poll(proposal.id, receipt.poll_option)
+                        .update(|current| *current -= &receipt.weight);
+                }
+
+                self.cast_poll_vote(proposal.id, option_id, receipt.weight.clone());
+            }
+
+            receipt.vote_type = vote_type.clone();
+            receipt.poll_option = option_id;
+            receipts_mapper.set(index, &receipt);
+        }
+
+        total_weight
+    }
+
+    /// Converts the weight a voter commits to a proposal into the weight counted on it.
+    /// With quadratic voting, the counted weight is the square root of all tokens the voter has committed to the proposal,
+    /// so splitting them across multiple votes does not gain any weight.
+    fn commit_effective_vote_weight(&self, voter: &ManagedAddress, proposal_id: u64, weight: BigUint) -> BigUint {
+        if !self.quadratic_voting().get() {
+            return weight;
+        }
+
+        let committed_mapper = self.quadratic_vote_tokens(proposal_id, voter);
+        let previously_committed = committed_mapper.get();
+        let committed = &previously_committed + &weight;
+
+        committed_mapper.set(&committed);
+
+        committed.sqrt() - previously_committed.sqrt()
+    }
+
+    fn require_vote_mode_allows_ballot(&self, voter: &ManagedAddress, proposal_id: u64) {
+        if self.vote_mode().get() != VoteMode::Single {
+            return;
+        }
+
+        require!(self.vote_receipts(proposal_id, voter).is_empty(), "already voted");
+    }
+
+    fn record_vote_receipt(&self, voter: &ManagedAddress, proposal_id: u64, vote_type: VoteType, weight: &BigUint, option_id: u8) {
+        if weight == &0 {
+            return;
+        }
+
+        self.vote_receipts(proposal_id, voter).push(&VoteReceipt {
+            vote_type,
+            weight: weight.clone(),
+            poll_option: option_id,
+        });
+    }
+
+    fn add_vote_weight(&self, proposal: &mut Proposal<Self::Api>, vote_type: &VoteType, weight: &BigUint) {
+        match vote_type {
+            VoteType::For => proposal.votes_for += weight,
+            VoteType::Against => proposal.votes_against += weight,
+            VoteType::Abstain => proposal.votes_abstain += weight,
+        }
+    }
+
+    fn remove_vote_weight(&self, proposal: &mut Proposal<Self::Api>, vote_type: &VoteType, weight: &BigUint) {
+        match vote_type {
+            VoteType::For => proposal.votes_for -= weight,
+            VoteType::Against => proposal.votes_against -= weight,
+            VoteType::Abstain => proposal.votes_abstain -= weight,
+        }
+    }
+
+    fn sign(&self, proposal_id: u64, option_id: u8) {
+        let mut proposal = self.proposals(proposal_id).get();
+        let status = self.get_proposal_status(&proposal);
+        require!(status == ProposalStatus::Active || status == ProposalStatus::Pending, "proposal is not active");
+
         let signer = self.blockchain().get_caller();
 
         self.sign_for_all_roles(&signer, &proposal);
+        self.queue_if_succeeded_early(&mut proposal);
+        self.proposals(proposal_id).set(&proposal);
         self.cast_poll_vote(proposal.id, option_id, BigUint::from(1u8));
         self.emit_sign_event(signer, &proposal, option_id);
     }
@@ -296,6 +895,8 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
     }
 
     fn cast_poll_vote(&self, proposal_id: u64, option_id: u8, weight: BigUint) {
+        self.require_valid_poll_option(proposal_id, option_id);
+
         if option_id == 0 || weight == 0 {
             return;
         }
@@ -342,6 +943,11 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             return Err(());
         }
 
+        if voter == &proposal.proposer {
+            let refund = !self.proposal_spam_flags(proposal_id).get() && self.has_proposal_earned_deposit_refund(&proposal, &status);
+            self.settle_proposal_deposit(&proposal, refund);
+        }
+
         let mut returnables: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
 
         // keep for backwards compatibility
@@ -373,6 +979,16 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         return Ok(());
     }
 
+    /// A proposal earns its deposit back once it has reached quorum or has passed, e.g. through role based policies.
+    /// Vetoed proposals never earn their deposit back.
+    fn has_proposal_earned_deposit_refund(&self, proposal: &Proposal<Self::Api>, status: &ProposalStatus) -> bool {
+        match status {
+            ProposalStatus::Vetoed => false,
+            ProposalStatus::Succeeded | ProposalStatus::Queued | ProposalStatus::Executed | ProposalStatus::Expired => true,
+            _ => self.get_quorum_votes(proposal) >= self.get_proposal_quorum(proposal),
+        }
+    }
+
     fn can_propose(&self, proposer: &ManagedAddress, actions_hash: &ManagedBuffer, permissions: &ManagedVec<ManagedBuffer>) -> (bool, ManagedVec<Policy<Self::Api>>) {
         // no actions -> always allowed
         if actions_hash.is_empty() && permissions.is_empty() {
@@ -529,7 +1145,58 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         current_time >= proposal.starts_at && current_time < proposal.ends_at
     }
 
-    fn has_sufficient_votes(&self, proposal: &Proposal<Self::Api>, quorum: &BigUint) -> bool {
+    /// Extends the voting period if a vote within its final window flipped the outcome the proposal would have if voting ended now.
+    /// The execution delay is shifted along, so the proposal stays queued for the same duration after the new end.
+    fn extend_voting_if_outcome_flipped(&self, proposal: &mut Proposal<Self::Api>, previous_outcome: ProposalStatus) {
+        let window_minutes = self.late_vote_window_in_minutes().get();
+
+        if window_minutes == 0 {
+            return;
+        }
+
+        let current_time = self.blockchain().get_block_timestamp();
+
+        if current_time + window_minutes as u64 * 60 < proposal.ends_at {
+            return;
+        }
+
+        if self.get_proposal_outcome(proposal) == previous_outcome {
+            return;
+        }
+
+        let extensions = self.proposal_extensions(proposal.id).get();
+
+        if extensions >= self.max_late_vote_extensions().get() {
+            return;
+        }
+
+        let extension = self.late_vote_extension_in_minutes().get() as u64 * 60;
+
+        proposal.ends_at += extension;
+
+        if proposal.executable_after != 0 {
+            proposal.executable_after += extension;
+        }
+
+        self.extend_delegation_locks(proposal);
+        self.extend_escrow_vote_locks(proposal);
+        self.proposal_extensions(proposal.id).set(extensions + 1);
+        self.extend_voting_event(proposal.id, proposal.ends_at, extensions + 1);
+    }
+
+    /// Returns the timestamp after which a succeeded proposal can no longer be executed. Returns 0 if proposals do not expire.
+    fn get_proposal_expires_at(&self, proposal: &Proposal<Self::Api>) -> u64 {
+        let grace_period_minutes = self.grace_period_in_minutes().get();
+
+        if grace_period_minutes == 0 {
+            return 0;
+        }
+
+        proposal.ends_at.max(proposal.executable_after) + grace_period_minutes as u64 * 60
+    }
+
+    /// Abstain votes never count toward the pass ratio, only toward the quorum depending on the quorum mode.
+    fn has_sufficient_votes(&self, proposal: &Proposal<Self::Api>, quorum: &BigUint, pass_threshold: u64) -> bool {
         let total_votes = &proposal.votes_for + &proposal.votes_against;
 
         if total_votes < 1 {
@@ -537,21 +1204,257 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         }
 
         let vote_for_percent = &proposal.votes_for * &BigUint::from(100u64) / &total_votes;
-        let vote_for_percent_to_pass = BigUint::from(50u64);
+        let vote_for_percent_to_pass = BigUint::from(pass_threshold);
 
-        vote_for_percent >= vote_for_percent_to_pass && &proposal.votes_for >= quorum
+        vote_for_percent >= vote_for_percent_to_pass && &self.get_quorum_votes(proposal) >= quorum
+    }
+
+    fn has_proposal_sufficient_votes(&self, proposal: &Proposal<Self::Api>) -> bool {
+        self.has_sufficient_votes(proposal, &self.get_proposal_quorum(proposal), self.get_proposal_pass_threshold(proposal))
+    }
+
+    fn get_quorum_votes(&self, proposal: &Proposal<Self::Api>) -> BigUint {
+        let quorum_mode = match &proposal.snapshot {
+            Some(snapshot) => snapshot.quorum_mode,
+            None => self.quorum_mode().get(),
+        };
+
+        match quorum_mode {
+            QuorumMode::ForOnly => proposal.votes_for.clone(),
+            QuorumMode::ForAndAgainst => &proposal.votes_for + &proposal.votes_against,
+            QuorumMode::All => &proposal.votes_for + &proposal.votes_against + &proposal.votes_abstain,
+        }
     }
 
     fn has_signer_majority_for_role(&self, proposal: &Proposal<Self::Api>, role: &ManagedBuffer) -> bool {
         let signer_count = self.proposal_signers(proposal.id, &role).len();
+        let signer_majority = self.get_proposal_role_member_amount(proposal, role) / 2 + 1;
 
-        signer_count > 0 && signer_count >= self.get_signer_majority_for_role(&role)
+        signer_count > 0 && signer_count >= signer_majority
     }
 
     fn get_signer_majority_for_role(&self, role: &ManagedBuffer) -> usize {
         self.roles_member_amount(&role).get() / 2 + 1
     }
 
+    fn take_proposal_snapshot(&self, proposer: &ManagedAddress, permissions: &ManagedVec<ManagedBuffer>) -> ProposalSnapshot<Self::Api> {
+        let proposer_id = self.users().get_user_id(proposer);
+        let mut roles = ManagedVec::new();
+        let mut policies = ManagedVec::new();
+
+        if proposer_id != 0 {
+            for role in self.user_roles(proposer_id).iter() {
+                for permission in permissions.iter() {
+                    if let Some(policy) = self.policies(&role).get(&permission) {
+                        policies.push(PolicySnapshot {
+                            role: role.clone(),
+                            permission: permission.clone_value(),
+                            method: policy.method,
+                            pass_threshold: self.get_policy_pass_threshold(&policy),
+                            quorum: policy.quorum,
+                        });
+                    }
+                }
+
+                roles.push(RoleSnapshot {
+                    member_amount: self.roles_member_amount(&role).get(),
+                    role,
+                });
+            }
+        }
+
+        ProposalSnapshot {
+            quorum: self.get_effective_quorum(),
+            pass_threshold: self.get_pass_threshold(),
+            quorum_mode: self.quorum_mode().get(),
+            is_leaderless: self.is_leaderless(),
+            roles,
+            policies,
+        }
+    }
+
+    /// Returns the quorum for proposals created now.
+    /// With a dynamic quorum, it is the average turnout of the most recently finished proposals within the configured bounds.
+    fn get_effective_quorum(&self) -> BigUint {
+        if self.dynamic_quorum().is_empty() {
+            return self.get_quorum();
+        }
+
+        let dynamic_quorum = self.dynamic_quorum().get();
+        let quorum = self.get_average_turnout(dynamic_quorum.sample_size).unwrap_or_else(|| self.get_quorum());
+
+        if quorum < dynamic_quorum.min {
+            return dynamic_quorum.min;
+        }
+
+        if quorum > dynamic_quorum.max {
+            return dynamic_quorum.max;
+        }
+
+        quorum
+    }
+
+    /// Averages the turnout of up to `sample_size` of the most recently finished proposals.
+    /// Finished proposals whose turnout has not been recorded yet are included.
+    fn get_average_turnout(&self, sample_size: usize) -> Option<BigUint> {
+        let mut total = BigUint::zero();
+        let mut samples = 0;
+
+        let finished = self.get_finished_pending_proposals();
+        let mut index = finished.len();
+
+        while samples < sample_size && index > 0 {
+            index -= 1;
+
+            if let Some(turnout) = self.get_finished_proposal_turnout(finished.get(index)) {
+                total += turnout;
+                samples += 1;
+            }
+        }
+
+        let turnouts = self.proposal_turnouts();
+        let recorded_samples = (sample_size - samples).min(turnouts.len());
+
+        for turnout in turnouts.iter().skip(turnouts.len() - recorded_samples) {
+            total += turnout;
+        }
+
+        samples += recorded_samples;
+
+        if samples == 0 {
+            return None;
+        }
+
+        Some(total / samples as u64)
+    }
+
+    /// Moves the turnout of proposals whose voting period has ended into the turnout history of the dynamic quorum.
+    /// The history is kept in the order the proposals ended in and only holds as many turnouts as the max sample size.
+    fn record_proposal_turnouts(&self) {
+        let mut pending = self.dynamic_quorum_pending_proposals();
+        let mut turnouts = self.proposal_turnouts();
+
+        for proposal_id in self.get_finished_pending_proposals().iter() {
+            if let Some(turnout) = self.get_finished_proposal_turnout(proposal_id) {
+                turnouts.push_back(turnout);
+
+                if turnouts.len() > DYNAMIC_QUORUM_SAMPLE_SIZE_MAX {
+                    turnouts.pop_front();
+                }
+            }
+
+            pending.swap_remove(&proposal_id);
+        }
+    }
+
+    fn track_proposal_turnout(&self, proposal_id: u64) {
+        if self.dynamic_quorum().is_empty() {
+            return;
+        }
+
+        self.dynamic_quorum_pending_proposals().insert(proposal_id);
+    }
+
+    /// Returns the tracked proposals whose voting period has ended or that have been canceled, ordered by when they ended.
+    fn get_finished_pending_proposals(&self) -> ManagedVec<u64> {
+        let current_time = self.blockchain().get_block_timestamp();
+        let mut finished = ManagedVec::<Self::Api, u64>::new();
+        let mut finished_ends_at = ManagedVec::<Self::Api, u64>::new();
+
+        for proposal_id in self.dynamic_quorum_pending_proposals().iter() {
+            let ends_at = self.proposals(proposal_id).get().ends_at;
+
+            if ends_at != 0 && current_time < ends_at {
+                continue;
+            }
+
+            let mut sorted = ManagedVec::new();
+            let mut sorted_ends_at = ManagedVec::new();
+            let mut inserted = false;
+
+            for (other_id, other_ends_at) in finished.iter().zip(finished_ends_at.iter()) {
+                if !inserted && ends_at < other_ends_at {
+                    sorted.push(proposal_id);
+                    sorted_ends_at.push(ends_at);
+                    inserted = true;
+                }
+
+                sorted.push(other_id);
+                sorted_ends_at.push(other_ends_at);
+            }
+
+            if !inserted {
+                sorted.push(proposal_id);
+                sorted_ends_at.push(ends_at);
+            }
+
+            finished = sorted;
+            finished_ends_at = sorted_ends_at;
+        }
+
+        finished
+    }
+
+    /// Returns the votes counting toward the quorum of a proposal whose voting period has ended. Canceled proposals have no turnout.
+    fn get_finished_proposal_turnout(&self, proposal_id: u64) -> Option<BigUint> {
+        let proposal = self.proposals(proposal_id).get();
+
+        if proposal.ends_at == 0 || self.blockchain().get_block_timestamp() < proposal.ends_at {
+            return None;
+        }
+
+        Some(self.get_quorum_votes(&proposal))
+    }
+
+    fn get_proposal_quorum(&self, proposal: &Proposal<Self::Api>) -> BigUint {
+        match &proposal.snapshot {
+            Some(snapshot) => snapshot.quorum.clone(),
+            None => self.get_quorum(),
+        }
+    }
+
+    fn get_proposal_pass_threshold(&self, proposal: &Proposal<Self::Api>) -> u64 {
+        match &proposal.snapshot {
+            Some(snapshot) => snapshot.pass_threshold,
+            None => self.get_pass_threshold(),
+        }
+    }
+
+    fn is_proposal_leaderless(&self, proposal: &Proposal<Self::Api>) -> bool {
+        match &proposal.snapshot {
+            Some(snapshot) => snapshot.is_leaderless,
+            None => self.is_leaderless(),
+        }
+    }
+
+    /// Returns the roles the proposer held when the proposal was created.
+    fn get_proposal_roles(&self, proposal: &Proposal<Self::Api>) -> ManagedVec<ManagedBuffer> {
+        match &proposal.snapshot {
+            Some(snapshot) => snapshot.roles.iter().map(|r| r.role).collect(),
+            None => self.user_roles(self.users().get_user_id(&proposal.proposer)).iter().collect(),
+        }
+    }
+
+    fn get_proposal_role_member_amount(&self, proposal: &Proposal<Self::Api>, role: &ManagedBuffer) -> usize {
+        match &proposal.snapshot {
+            Some(snapshot) => snapshot.roles.iter().find(|r| &r.role == role).map(|r| r.member_amount).unwrap_or_default(),
+            None => self.roles_member_amount(role).get(),
+        }
+    }
+
+    fn get_proposal_policy(&self, proposal: &Proposal<Self::Api>, role: &ManagedBuffer, permission: &ManagedBuffer) -> Option<PolicySnapshot<Self::Api>> {
+        match &proposal.snapshot {
+            Some(snapshot) => snapshot.policies.iter().find(|p| &p.role == role && &p.permission == permission),
+            None => self.policies(role).get(permission).map(|policy| PolicySnapshot {
+                role: role.clone(),
+                permission: permission.clone(),
+                method: policy.method,
+                pass_threshold: self.get_policy_pass_threshold(&policy),
+                quorum: policy.quorum,
+            }),
+        }
+    }
+
     fn require_proposed_via_trusted_host(
         &self,
         proposer: &ManagedAddress,
@@ -560,6 +1463,7 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
         content_sig: ManagedBuffer,
         actions_hash: &ManagedBuffer,
         permissions: &ManagedVec<ManagedBuffer>,
+        poll: &Option<Poll<Self::Api>>,
     ) {
         let entity_address = self.blockchain().get_sc_address();
         let trusted_host_signature = ManagedByteArray::try_from(content_sig).unwrap_or_default();
@@ -577,6 +1481,16 @@ pub trait ProposalModule: config::ConfigModule + permission::PermissionModule +
             trusted_host_signable.append(&perm);
         }
 
+        if let Some(poll) = poll {
+            for option_hash in poll.option_hashes.into_iter() {
+                trusted_host_signable.append(&option_hash);
+            }
+
+            for option_actions_hash in poll.option_actions_hashes.into_iter() {
+                trusted_host_signable.append(&option_actions_hash);
+            }
+        }
+
         self.require_signed_by_trusted_host(&trusted_host_signable, &trusted_host_signature);
     }
 
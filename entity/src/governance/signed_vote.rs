@@ -0,0 +1,67 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc::api::ED25519_SIGNATURE_BYTE_LEN;
+
+use super::proposal::VoteType;
+use crate::config;
+
+/// A vote signed by the voter off-chain and submitted by a relayer on their behalf.
+/// - min_weight: the min weight the vote must be cast with. All of the voter's available escrowed and delegated weight is cast, which may exceed it
+/// - nonce: must be greater than the voter's last used nonce
+/// - expires_at: the timestamp after which the vote can no longer be submitted
+/// - signature: the voter's ed25519 signature over the vote message
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct SignedVote<M: ManagedTypeApi> {
+    pub voter: ManagedAddress<M>,
+    pub proposal_id: u64,
+    pub vote_type: VoteType,
+    pub option_id: u8,
+    pub min_weight: BigUint<M>,
+    pub nonce: u64,
+    pub expires_at: u64,
+    pub signature: ManagedByteArray<M, ED25519_SIGNATURE_BYTE_LEN>,
+}
+
+#[multiversx_sc::module]
+pub trait SignedVoteModule: config::ConfigModule {
+    /// Verifies that a relayed vote has been signed by the voter, has not expired and has not been submitted before.
+    /// Marks the vote's nonce as used, which invalidates all of the voter's signed votes with a lower or equal nonce.
+    fn require_valid_signed_vote(&self, vote: &SignedVote<Self::Api>) {
+        require!(!self.signed_vote_chain_id().is_empty(), "signed votes are not enabled");
+        require!(self.blockchain().get_block_timestamp() <= vote.expires_at, "signed vote expired");
+        require!(vote.nonce > self.signed_vote_nonces(&vote.voter).get(), "invalid signed vote nonce");
+
+        let signable = self.get_signed_vote_signable(vote);
+        let signable_hashed = self.crypto().keccak256(&signable);
+
+        self.crypto()
+            .verify_ed25519(vote.voter.as_managed_buffer(), signable_hashed.as_managed_buffer(), vote.signature.as_managed_buffer());
+
+        self.signed_vote_nonces(&vote.voter).set(vote.nonce);
+    }
+
+    /// Builds the message a voter signs. Numbers are encoded with a fixed width and the weight comes last, so no two votes share a message.
+    /// Includes the chain id, so a vote signed for one chain can not be replayed on another.
+    fn get_signed_vote_signable(&self, vote: &SignedVote<Self::Api>) -> ManagedBuffer {
+        let entity_address = self.blockchain().get_sc_address();
+        let chain_id = self.signed_vote_chain_id().get();
+        let mut signable = sc_format!("{}{}{}", vote.voter.as_managed_buffer(), entity_address.as_managed_buffer(), chain_id);
+
+        signable.append_bytes(&vote.proposal_id.to_be_bytes());
+        signable.append_bytes(&[vote.vote_type.clone() as u8, vote.option_id]);
+        signable.append_bytes(&vote.nonce.to_be_bytes());
+        signable.append_bytes(&vote.expires_at.to_be_bytes());
+        signable.append(&vote.min_weight.to_bytes_be_buffer());
+
+        signable
+    }
+
+    #[view(getSignedVoteChainId)]
+    #[storage_mapper("signed_vote:chain_id")]
+    fn signed_vote_chain_id(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[view(getSignedVoteNonce)]
+    #[storage_mapper("signed_vote:nonces")]
+    fn signed_vote_nonces(&self, voter: &ManagedAddress) -> SingleValueMapper<u64>;
+}
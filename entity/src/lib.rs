@@ -2,6 +2,8 @@
 
 multiversx_sc::imports!();
 
+use permission::ROLE_BUILTIN_GUARDIAN;
+
 pub mod config;
 pub mod contract;
 pub mod governance;
@@ -15,13 +17,19 @@ pub trait Entity:
     + permission::PermissionModule
     + plug::PlugModule
     + governance::GovernanceModule
+    + governance::delegation::DelegationModule
+    + governance::deposit::DepositModule
+    + governance::escrow::EscrowModule
     + governance::events::GovEventsModule
+    + governance::poll::PollModule
     + governance::proposal::ProposalModule
+    + governance::signed_vote::SignedVoteModule
     + governance::token::TokenModule
 {
     #[init]
     fn init(&self, trusted_host_address: ManagedAddress, opt_leader: OptionalValue<ManagedAddress>) {
         self.trusted_host_address().set(&trusted_host_address);
+        self.guardian_role().set(ManagedBuffer::from(ROLE_BUILTIN_GUARDIAN));
         self.init_governance_module();
 
         if let OptionalValue::Some(leader) = opt_leader {
@@ -38,6 +46,12 @@ pub trait Entity:
         self.lock_vote_tokens(&token).set(lock);
     }
 
+    #[endpoint(setSignedVoteChainId)]
+    fn set_signed_vote_chain_id_endpoint(&self, chain_id: ManagedBuffer) {
+        self.require_caller_trusted_host();
+        self.signed_vote_chain_id().set(chain_id);
+    }
+
     #[payable("EGLD")]
     #[endpoint(registerDns)]
     fn register_dns(&self, dns_address: ManagedAddress, name: ManagedBuffer) {
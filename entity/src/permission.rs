@@ -1,10 +1,12 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::governance::proposal::decode_optional_field;
 use crate::{config, plug};
 
 pub const ROLE_BUILTIN_LEADER: &[u8] = b"leader";
 pub const ROLE_BUILTIN_DEVELOPER: &[u8] = b"developer";
+pub const ROLE_BUILTIN_GUARDIAN: &[u8] = b"guardian";
 
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
 pub struct PermissionDetails<M: ManagedTypeApi> {
@@ -15,11 +17,38 @@ pub struct PermissionDetails<M: ManagedTypeApi> {
     pub payments: ManagedVec<M, EsdtTokenPayment<M>>,
 }
 
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+#[derive(TopEncode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
 pub struct Policy<M: ManagedTypeApi> {
     pub method: PolicyMethod,
     pub quorum: BigUint<M>,
     pub voting_period_minutes: usize,
+    pub pass_threshold: u64, // 0 falls back to the entity's pass threshold
+    pub execution_delay_minutes: usize,
+}
+
+// fields appended after `voting_period_minutes` are optional to keep decoding policies stored by earlier versions.
+impl<M: ManagedTypeApi> TopDecode for Policy<M> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> core::result::Result<Self, H::HandledErr>
+    where
+        I: codec::TopDecodeInput,
+        H: codec::DecodeErrorHandler,
+    {
+        let mut input = input.into_nested_buffer();
+
+        let policy = Policy {
+            method: PolicyMethod::dep_decode_or_handle_err(&mut input, h)?,
+            quorum: BigUint::dep_decode_or_handle_err(&mut input, h)?,
+            voting_period_minutes: usize::dep_decode_or_handle_err(&mut input, h)?,
+            pass_threshold: decode_optional_field(&mut input, h)?,
+            execution_delay_minutes: decode_optional_field(&mut input, h)?,
+        };
+
+        if !codec::NestedDecodeInput::is_depleted(&input) {
+            return core::result::Result::Err(h.handle_error(codec::DecodeError::INPUT_TOO_LONG));
+        }
+
+        core::result::Result::Ok(policy)
+    }
 }
 
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Debug, ManagedVecItem)]
@@ -28,6 +57,7 @@ pub enum PolicyMethod {
     One,
     All,
     Quorum,
+    Optimistic,
 }
 
 impl PolicyMethod {
@@ -37,6 +67,7 @@ impl PolicyMethod {
             PolicyMethod::One => b"one",
             PolicyMethod::All => b"all",
             PolicyMethod::Quorum => b"quorum",
+            PolicyMethod::Optimistic => b"optimistic",
         }
     }
 }
@@ -81,6 +112,24 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
         self.unassign_role(address, role_name);
     }
 
+    /// Set the role whose members act as guardians, e.g. to flag proposals as spam or veto proposals.
+    /// Defaults to the built-in guardian role.
+    /// Can only be called by the contract itself.
+    #[endpoint(setGuardianRole)]
+    fn set_guardian_role_endpoint(&self, role_name: ManagedBuffer) {
+        self.require_caller_self();
+        require!(self.roles().contains(&role_name), "role does not exist");
+        self.guardian_role().set(role_name);
+    }
+
+    /// Remove the guardian role, which revokes all guardian privileges.
+    /// Can only be called by the contract itself.
+    #[endpoint(removeGuardianRole)]
+    fn remove_guardian_role_endpoint(&self) {
+        self.require_caller_self();
+        self.guardian_role().clear();
+    }
+
     /// Create a general permission.
     /// This permission can later be connected to custom roles through a policy.
     /// Can only be called by the contract itself.
@@ -105,11 +154,51 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
     }
 
     /// Create a policy that requires role members to vote based on the provided parameters in order to invoke the permission.
+    /// The optional pass threshold is the percentage of FOR votes required to pass and defaults to the entity's pass threshold.
     /// Can only be called by the contract itself.
     #[endpoint(createPolicyWeighted)]
-    fn create_policy_weighted_endpoint(&self, role_name: ManagedBuffer, permission_name: ManagedBuffer, quorum: BigUint, voting_period_minutes: usize) {
+    fn create_policy_weighted_endpoint(
+        &self,
+        role_name: ManagedBuffer,
+        permission_name: ManagedBuffer,
+        quorum: BigUint,
+        voting_period_minutes: usize,
+        opt_pass_threshold: OptionalValue<u64>,
+    ) {
         self.require_caller_self();
-        self.create_policy(role_name, permission_name, PolicyMethod::Weight, quorum, voting_period_minutes);
+
+        let pass_threshold = opt_pass_threshold.into_option().unwrap_or_default();
+
+        if pass_threshold != 0 {
+            self.require_valid_pass_threshold(pass_threshold);
+        }
+
+        self.insert_policy(
+            role_name,
+            permission_name,
+            Policy {
+                method: PolicyMethod::Weight,
+                quorum,
+                voting_period_minutes,
+                pass_threshold,
+                execution_delay_minutes: 0,
+            },
+        );
+    }
+
+    /// Change the minimum delay between success and execution of proposals governed by the policy.
+    /// The longest delay of the entity and all applied policies is used.
+    /// Can only be called by the contract itself.
+    #[endpoint(changePolicyExecutionDelay)]
+    fn change_policy_execution_delay_endpoint(&self, role_name: ManagedBuffer, permission_name: ManagedBuffer, execution_delay_minutes: usize) {
+        self.require_caller_self();
+        self.require_valid_execution_delay(execution_delay_minutes);
+
+        let mut policies = self.policies(&role_name);
+        let mut policy = policies.get(&permission_name).unwrap_or_else(|| sc_panic!("policy does not exist"));
+
+        policy.execution_delay_minutes = execution_delay_minutes;
+        policies.insert(permission_name, policy);
     }
 
     /// Create a policy that allows permissions to be invoked unilaterally.
@@ -142,6 +231,17 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
         );
     }
 
+    /// Create a policy that lets role members invoke the permission unless token holders object.
+    /// Proposals pass at the end of the voting period if their AGAINST votes stay below the objection threshold.
+    /// Can only be called by the contract itself.
+    #[endpoint(createPolicyOptimistic)]
+    fn create_policy_optimistic_endpoint(&self, role_name: ManagedBuffer, permission_name: ManagedBuffer, objection_threshold: BigUint, voting_period_minutes: usize) {
+        self.require_caller_self();
+        require!(objection_threshold > 0, "invalid objection threshold");
+
+        self.create_policy(role_name, permission_name, PolicyMethod::Optimistic, objection_threshold, voting_period_minutes);
+    }
+
     #[view(getUserRoles)]
     fn get_user_roles_view(&self, address: ManagedAddress) -> MultiValueEncoded<ManagedBuffer> {
         let user_id = self.users().get_user_id(&address);
@@ -188,16 +288,20 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
     }
 
     #[view(getPolicies)]
-    fn get_policies_view(&self, role_name: ManagedBuffer) -> MultiValueEncoded<MultiValue4<ManagedBuffer, ManagedBuffer, BigUint, usize>> {
+    fn get_policies_view(&self, role_name: ManagedBuffer) -> MultiValueEncoded<MultiValue6<ManagedBuffer, ManagedBuffer, BigUint, usize, u64, usize>> {
         let mut policies = MultiValueEncoded::new();
 
         for (permission_name, policy) in self.policies(&role_name).iter() {
+            let pass_threshold = self.get_policy_pass_threshold(&policy);
+
             policies.push(
                 (
                     permission_name,
                     ManagedBuffer::from(policy.method.to_name()),
                     policy.quorum,
                     policy.voting_period_minutes,
+                    pass_threshold,
+                    policy.execution_delay_minutes,
                 )
                     .into(),
             );
@@ -218,6 +322,10 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
         self.roles().swap_remove(&role_name);
         self.roles_member_amount(&role_name).set(0);
 
+        if self.guardian_role().get() == role_name {
+            self.guardian_role().clear();
+        }
+
         for user_id in 1..=self.users().get_user_count() {
             self.user_roles(user_id).swap_remove(&role_name);
         }
@@ -304,20 +412,36 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
     }
 
     fn create_policy(&self, role_name: ManagedBuffer, permission_name: ManagedBuffer, method: PolicyMethod, quorum: BigUint, voting_period_minutes: usize) {
-        require!(self.roles().contains(&role_name), "role does not exist");
-        require!(self.permissions().contains(&permission_name), "permission does not exist");
-        require!(!self.policies(&role_name).contains_key(&permission_name), "policy already exists");
-
-        self.policies(&role_name).insert(
+        self.insert_policy(
+            role_name,
             permission_name,
             Policy {
                 method,
                 quorum,
                 voting_period_minutes,
+                pass_threshold: 0,
+                execution_delay_minutes: 0,
             },
         );
     }
 
+    fn insert_policy(&self, role_name: ManagedBuffer, permission_name: ManagedBuffer, policy: Policy<Self::Api>) {
+        require!(self.roles().contains(&role_name), "role does not exist");
+        require!(self.permissions().contains(&permission_name), "permission does not exist");
+        require!(!self.policies(&role_name).contains_key(&permission_name), "policy already exists");
+
+        self.policies(&role_name).insert(permission_name, policy);
+    }
+
+    /// Returns the percentage of FOR votes required to pass a proposal governed by the given policy.
+    fn get_policy_pass_threshold(&self, policy: &Policy<Self::Api>) -> u64 {
+        if policy.pass_threshold == 0 {
+            return self.get_pass_threshold();
+        }
+
+        policy.pass_threshold
+    }
+
     fn has_role(&self, address: &ManagedAddress, role_name: &ManagedBuffer) -> bool {
         let user_id = self.users().get_user_id(&address);
 
@@ -350,6 +474,14 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
         require!(self.has_leader_role(&caller), "caller must be leader");
     }
 
+    fn is_guardian(&self, address: &ManagedAddress) -> bool {
+        if self.guardian_role().is_empty() {
+            return false;
+        }
+
+        self.has_role(address, &self.guardian_role().get())
+    }
+
     #[view(getRoles)]
     #[storage_mapper("roles")]
     fn roles(&self) -> UnorderedSetMapper<ManagedBuffer<Self::Api>>;
@@ -369,4 +501,8 @@ pub trait PermissionModule: config::ConfigModule + plug::PlugModule {
 
     #[storage_mapper("policies")]
     fn policies(&self, role_name: &ManagedBuffer) -> MapMapper<ManagedBuffer<Self::Api>, Policy<Self::Api>>;
+
+    #[view(getGuardianRole)]
+    #[storage_mapper("guardian_role")]
+    fn guardian_role(&self) -> SingleValueMapper<ManagedBuffer<Self::Api>>;
 }
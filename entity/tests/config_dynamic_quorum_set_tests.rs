@@ -0,0 +1,99 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_sets_the_dynamic_quorum_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_dynamic_quorum_endpoint(managed_biguint!(10), managed_biguint!(100), 5);
+
+            let dynamic_quorum = sc.dynamic_quorum().get();
+
+            assert_eq!(managed_biguint!(10), dynamic_quorum.min);
+            assert_eq!(managed_biguint!(100), dynamic_quorum.max);
+            assert_eq!(5, dynamic_quorum.sample_size);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_removes_the_dynamic_quorum() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_dynamic_quorum_endpoint(managed_biguint!(10), managed_biguint!(100), 5);
+            sc.remove_dynamic_quorum_endpoint();
+
+            assert!(sc.dynamic_quorum().is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_min_is_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_dynamic_quorum_endpoint(managed_biguint!(0), managed_biguint!(100), 5);
+        })
+        .assert_user_error("invalid dynamic quorum bounds");
+}
+
+#[test]
+fn it_fails_when_max_is_below_min() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_dynamic_quorum_endpoint(managed_biguint!(100), managed_biguint!(10), 5);
+        })
+        .assert_user_error("invalid dynamic quorum bounds");
+}
+
+#[test]
+fn it_fails_when_sample_size_is_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_dynamic_quorum_endpoint(managed_biguint!(10), managed_biguint!(100), 0);
+        })
+        .assert_user_error("invalid dynamic quorum sample size");
+}
+
+#[test]
+fn it_fails_when_sample_size_exceeds_max() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_dynamic_quorum_endpoint(managed_biguint!(10), managed_biguint!(100), DYNAMIC_QUORUM_SAMPLE_SIZE_MAX + 1);
+        })
+        .assert_user_error("invalid dynamic quorum sample size");
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_dynamic_quorum_endpoint(managed_biguint!(10), managed_biguint!(100), 5);
+        })
+        .assert_user_error("action not allowed by user");
+}
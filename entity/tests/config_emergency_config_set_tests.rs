@@ -0,0 +1,160 @@
+use entity::config::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_sets_the_emergency_config_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_emergency_config_endpoint(60, managed_biguint!(100), 66, 30);
+
+            let emergency_config = sc.emergency_config().get();
+
+            assert_eq!(60, emergency_config.voting_period_minutes);
+            assert_eq!(managed_biguint!(100), emergency_config.quorum);
+            assert_eq!(66, emergency_config.pass_threshold);
+            assert_eq!(30, emergency_config.execution_delay_minutes);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_removes_the_emergency_config() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_emergency_config_endpoint(60, managed_biguint!(100), 66, 0);
+            sc.remove_emergency_config_endpoint();
+
+            assert!(sc.emergency_config().is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_voting_period_is_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_emergency_config_endpoint(0, managed_biguint!(100), 66, 0);
+        })
+        .assert_user_error("voting period can not be zero");
+}
+
+#[test]
+fn it_fails_when_voting_period_exceeds_max() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_emergency_config_endpoint(VOTING_PERIOD_MINUTES_MAX + 1, managed_biguint!(100), 66, 0);
+        })
+        .assert_user_error("max voting period exceeded");
+}
+
+#[test]
+fn it_fails_when_quorum_is_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_emergency_config_endpoint(60, managed_biguint!(0), 66, 0);
+        })
+        .assert_user_error("invalid quorum");
+}
+
+#[test]
+fn it_fails_when_pass_threshold_is_invalid() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_emergency_config_endpoint(60, managed_biguint!(100), 101, 0);
+        })
+        .assert_user_error("invalid pass threshold");
+}
+
+#[test]
+fn it_fails_when_execution_delay_exceeds_max() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_emergency_config_endpoint(60, managed_biguint!(100), 66, EXECUTION_DELAY_MINUTES_MAX + 1);
+        })
+        .assert_user_error("max execution delay exceeded");
+}
+
+#[test]
+fn it_sets_the_emergency_permissions() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let sc_address = setup.contract.address_ref().clone();
+
+    setup
+        .blockchain
+        .execute_tx(&sc_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_permission(
+                managed_buffer!(b"otherperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"otherendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+
+            sc.set_emergency_permissions_endpoint(MultiValueEncoded::from(ManagedVec::from(vec![managed_buffer!(b"otherperm")])));
+            sc.set_emergency_permissions_endpoint(MultiValueEncoded::from(ManagedVec::from(vec![managed_buffer!(b"testperm")])));
+
+            assert_eq!(1, sc.emergency_permissions().len());
+            assert!(sc.emergency_permissions().contains(&managed_buffer!(b"testperm")));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_set_emergency_permissions_that_do_not_exist() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_emergency_permissions_endpoint(MultiValueEncoded::from(ManagedVec::from(vec![managed_buffer!(b"unknown")])));
+        })
+        .assert_user_error("permission does not exist");
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_emergency_config_endpoint(60, managed_biguint!(100), 66, 0);
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,44 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_changes_the_grace_period_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_grace_period_endpoint(60);
+
+            assert_eq!(60, sc.grace_period_in_minutes().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_the_grace_period_exceeds_the_max() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_grace_period_endpoint(GRACE_PERIOD_MINUTES_MAX + 1);
+        })
+        .assert_user_error("max grace period exceeded");
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_grace_period_endpoint(60);
+        })
+        .assert_user_error("action not allowed by user");
+}
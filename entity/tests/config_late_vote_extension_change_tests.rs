@@ -0,0 +1,97 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_changes_the_late_vote_extension_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_late_vote_extension_endpoint(60, 30, 2);
+
+            assert_eq!(sc.late_vote_window_in_minutes().get(), 60);
+            assert_eq!(sc.late_vote_extension_in_minutes().get(), 30);
+            assert_eq!(sc.max_late_vote_extensions().get(), 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_disables_the_late_vote_extension_with_a_zero_window() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_late_vote_extension_endpoint(60, 30, 2);
+            sc.change_late_vote_extension_endpoint(0, 0, 0);
+
+            assert_eq!(sc.late_vote_window_in_minutes().get(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_extension_is_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_late_vote_extension_endpoint(60, 0, 2);
+        })
+        .assert_user_error("invalid late vote extension");
+}
+
+#[test]
+fn it_fails_when_max_extensions_is_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_late_vote_extension_endpoint(60, 30, 0);
+        })
+        .assert_user_error("invalid late vote extension");
+}
+
+#[test]
+fn it_fails_when_window_exceeds_max_voting_period() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_late_vote_extension_endpoint(VOTING_PERIOD_MINUTES_MAX + 1, 30, 2);
+        })
+        .assert_user_error("max late vote window exceeded");
+}
+
+#[test]
+fn it_fails_when_extension_exceeds_max_voting_period() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_late_vote_extension_endpoint(60, VOTING_PERIOD_MINUTES_MAX + 1, 2);
+        })
+        .assert_user_error("max late vote extension exceeded");
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_late_vote_extension_endpoint(60, 30, 2);
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,60 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_changes_the_pass_threshold_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            assert_eq!(PASS_THRESHOLD_PERCENT_DEFAULT, sc.get_pass_threshold());
+
+            sc.change_pass_threshold_endpoint(66);
+
+            assert_eq!(66, sc.get_pass_threshold());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_below_simple_majority() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_pass_threshold_endpoint(49);
+        })
+        .assert_user_error("invalid pass threshold");
+}
+
+#[test]
+fn it_fails_when_above_one_hundred_percent() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_pass_threshold_endpoint(101);
+        })
+        .assert_user_error("invalid pass threshold");
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_pass_threshold_endpoint(66);
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,60 @@
+use entity::governance::deposit::*;
+use entity::governance::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_sets_the_proposal_deposit_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_proposal_deposit_endpoint(EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(ENTITY_GOV_TOKEN_ID)), managed_biguint!(10), true);
+
+            let deposit = sc.proposal_deposit().get();
+
+            assert_eq!(EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(ENTITY_GOV_TOKEN_ID)), deposit.token_id);
+            assert_eq!(managed_biguint!(10), deposit.amount);
+            assert!(deposit.burn_on_slash);
+
+            sc.remove_proposal_deposit_endpoint();
+
+            assert!(sc.proposal_deposit().is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_set_an_invalid_proposal_deposit() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_proposal_deposit_endpoint(EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(ENTITY_GOV_TOKEN_ID)), managed_biguint!(0), false);
+        })
+        .assert_user_error("invalid deposit amount");
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_proposal_deposit_endpoint(EgldOrEsdtTokenIdentifier::egld(), managed_biguint!(10), true);
+        })
+        .assert_user_error("egld deposits can not be burned");
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_proposal_deposit_endpoint(EgldOrEsdtTokenIdentifier::egld(), managed_biguint!(10), false);
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,34 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_enables_quadratic_voting_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            assert!(!sc.quadratic_voting().get());
+
+            sc.change_quadratic_voting_endpoint(true);
+
+            assert!(sc.quadratic_voting().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quadratic_voting_endpoint(true);
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,37 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_changes_the_quorum_mode_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            assert_eq!(QuorumMode::ForOnly, sc.quorum_mode().get());
+
+            sc.change_quorum_mode_endpoint(QuorumMode::All);
+
+            assert_eq!(QuorumMode::All, sc.quorum_mode().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_mode_endpoint(QuorumMode::All);
+        })
+        .assert_user_error("action not allowed by user");
+}
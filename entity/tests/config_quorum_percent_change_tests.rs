@@ -0,0 +1,152 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_changes_the_quorum_percent_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_percent_endpoint(10);
+
+            assert_eq!(10, sc.quorum_percent().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_calculates_the_quorum_from_the_circulating_supply() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    // treasury holds 200 tokens
+    setup
+        .blockchain
+        .set_esdt_balance(setup.contract.address_ref(), ENTITY_GOV_TOKEN_ID, &rust_biguint!(200));
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.gov_token_supply().set(managed_biguint!(1_200));
+            sc.change_quorum_percent_endpoint(10);
+
+            assert_eq!(managed_biguint!(1_000), sc.get_circulating_supply());
+            assert_eq!(managed_biguint!(100), sc.get_quorum());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_excludes_guarded_vote_tokens_from_the_circulating_supply() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    // treasury holds 150 tokens and 50 tokens are deposited as votes
+    setup
+        .blockchain
+        .set_esdt_balance(setup.contract.address_ref(), ENTITY_GOV_TOKEN_ID, &rust_biguint!(200));
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.gov_token_supply().set(managed_biguint!(1_200));
+            sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).set(managed_biguint!(50));
+            sc.change_quorum_percent_endpoint(10);
+
+            assert_eq!(managed_biguint!(1_000), sc.get_circulating_supply());
+            assert_eq!(managed_biguint!(100), sc.get_quorum());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_lowers_the_current_quorum_when_a_holder_deposits_vote_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.gov_token_supply().set(managed_biguint!(ENTITY_GOV_TOKEN_SUPPLY * 2));
+            sc.change_quorum_percent_endpoint(10);
+
+            assert_eq!(managed_biguint!(200), sc.get_quorum());
+        })
+        .assert_ok();
+
+    setup.propose(&voter_address, 500, b"id");
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_biguint!(500), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+            assert_eq!(managed_biguint!(150), sc.get_quorum());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_uses_the_absolute_quorum_when_quorum_percent_is_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_percent_endpoint(10);
+            sc.change_quorum_percent_endpoint(0);
+
+            assert_eq!(managed_biguint!(QURUM), sc.get_quorum());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_above_one_hundred_percent() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_percent_endpoint(101);
+        })
+        .assert_user_error("invalid quorum percent");
+}
+
+#[test]
+fn it_fails_when_gov_token_supply_is_not_tracked() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_percent_endpoint(10);
+        })
+        .assert_user_error("gov token supply not tracked");
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_percent_endpoint(10);
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,35 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_changes_the_vote_mode_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            assert_eq!(VoteMode::Cumulative, sc.vote_mode().get());
+
+            sc.change_vote_mode_endpoint(VoteMode::Single);
+
+            assert_eq!(VoteMode::Single, sc.vote_mode().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_mode_endpoint(VoteMode::Single);
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,116 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_sets_vote_nonce_weights_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(false);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let mut weights = MultiValueEncoded::new();
+            weights.push((1u64, managed_biguint!(10)).into());
+            weights.push((2u64, managed_biguint!(3)).into());
+            sc.set_vote_nonce_weights_endpoint(weights);
+
+            assert_eq!(Some(managed_biguint!(10)), sc.vote_nonce_weights().get(&1));
+            assert_eq!(Some(managed_biguint!(3)), sc.vote_nonce_weights().get(&2));
+            assert_eq!(2, sc.get_vote_nonce_weights_view().len());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_removes_vote_nonce_weights_set_to_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(false);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let mut weights = MultiValueEncoded::new();
+            weights.push((1u64, managed_biguint!(10)).into());
+            weights.push((2u64, managed_biguint!(3)).into());
+            sc.set_vote_nonce_weights_endpoint(weights);
+
+            let mut weights = MultiValueEncoded::new();
+            weights.push((1u64, managed_biguint!(0)).into());
+            weights.push((2u64, managed_biguint!(5)).into());
+            sc.set_vote_nonce_weights_endpoint(weights);
+
+            assert!(!sc.vote_nonce_weights().contains_key(&1));
+            assert_eq!(Some(managed_biguint!(5)), sc.vote_nonce_weights().get(&2));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_returns_the_effective_vote_nonce_weight() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(false);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            // without a weight table, every nonce counts by its raw amount
+            assert_eq!(managed_biguint!(1), sc.get_vote_nonce_weight_view(7));
+
+            let mut weights = MultiValueEncoded::new();
+            weights.push((1u64, managed_biguint!(10)).into());
+            sc.set_vote_nonce_weights_endpoint(weights);
+
+            assert_eq!(managed_biguint!(10), sc.get_vote_nonce_weight_view(1));
+            assert_eq!(managed_biguint!(0), sc.get_vote_nonce_weight_view(7));
+
+            sc.change_vote_nonce_weight_default_endpoint(managed_biguint!(2));
+
+            assert_eq!(managed_biguint!(2), sc.get_vote_nonce_weight_view(7));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_set_a_weight_for_nonce_zero() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(false);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let mut weights = MultiValueEncoded::new();
+            weights.push((0u64, managed_biguint!(10)).into());
+            sc.set_vote_nonce_weights_endpoint(weights);
+        })
+        .assert_user_error("invalid nonce");
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let mut weights = MultiValueEncoded::new();
+            weights.push((1u64, managed_biguint!(10)).into());
+            sc.set_vote_nonce_weights_endpoint(weights);
+        })
+        .assert_user_error("action not allowed by user");
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_nonce_weight_default_endpoint(managed_biguint!(1));
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,116 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const LP_TOKEN_ID: &[u8] = b"LPT-abcdef";
+
+#[test]
+fn it_registers_vote_tokens_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_vote_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 100, 18, true);
+            sc.set_vote_token_endpoint(managed_token_id!(LP_TOKEN_ID), 250, 6, false);
+
+            let lp_token = sc.vote_tokens().get(&managed_token_id!(LP_TOKEN_ID)).unwrap();
+            assert_eq!(250, lp_token.multiplier);
+            assert_eq!(6, lp_token.decimals);
+            assert!(!sc.lock_vote_tokens(&managed_token_id!(LP_TOKEN_ID)).get());
+
+            let (token_id, multiplier, decimals, lock_vote_tokens) = sc.get_vote_tokens_view().into_iter().next().unwrap().into_tuple();
+            assert_eq!(managed_token_id!(ENTITY_GOV_TOKEN_ID), token_id);
+            assert_eq!(100, multiplier);
+            assert_eq!(18, decimals);
+            assert!(lock_vote_tokens);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_register_a_vote_token_before_the_gov_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_vote_token_endpoint(managed_token_id!(LP_TOKEN_ID), 250, 6, false);
+        })
+        .assert_user_error("gov token must be registered first");
+}
+
+#[test]
+fn it_fails_to_register_a_vote_token_with_invalid_settings() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_vote_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 0, 18, true);
+        })
+        .assert_user_error("invalid multiplier");
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_vote_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 100, TOKEN_MAX_DECIMALS + 1, true);
+        })
+        .assert_user_error("invalid decimals");
+}
+
+#[test]
+fn it_removes_vote_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_vote_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 100, 18, true);
+            sc.set_vote_token_endpoint(managed_token_id!(LP_TOKEN_ID), 250, 6, false);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.remove_vote_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID));
+        })
+        .assert_user_error("other vote tokens depend on the gov token");
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.remove_vote_token_endpoint(managed_token_id!(LP_TOKEN_ID));
+            sc.remove_vote_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID));
+
+            assert!(sc.vote_tokens().is_empty());
+            assert!(!sc.is_vote_token(&managed_token_id!(LP_TOKEN_ID)));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_vote_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 100, 18, true);
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,44 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_changes_the_voting_delay_when_contract_calls_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_voting_delay_in_minutes_endpoint(60);
+
+            assert_eq!(60, sc.voting_delay_in_minutes().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_when_max_voting_delay_exceeded() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_voting_delay_in_minutes_endpoint(VOTING_DELAY_MINUTES_MAX + 1);
+        })
+        .assert_user_error("max voting delay exceeded");
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_voting_delay_in_minutes_endpoint(60);
+        })
+        .assert_user_error("action not allowed by user");
+}
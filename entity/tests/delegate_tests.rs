@@ -0,0 +1,448 @@
+use entity::config::*;
+use entity::governance::delegation::*;
+use entity::governance::*;
+use entity::plug::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_delegates_governance_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+
+            assert_eq!(managed_address!(&delegate_address), sc.delegate(&managed_address!(&delegator_address)).get());
+            assert_eq!(managed_biguint!(100), sc.get_delegated_vote_weight_view(managed_address!(&delegate_address)));
+            assert_eq!(managed_biguint!(100), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&delegator_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY - 100));
+}
+
+#[test]
+fn it_moves_the_delegation_to_another_delegate() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let first_delegate = setup.owner_address.clone();
+    let second_delegate = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&first_delegate));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.delegate_endpoint(managed_address!(&second_delegate));
+
+            assert_eq!(managed_biguint!(0), sc.get_delegated_vote_weight_view(managed_address!(&first_delegate)));
+            assert_eq!(managed_biguint!(100), sc.get_delegated_vote_weight_view(managed_address!(&second_delegate)));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_delegate_to_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegator_address));
+        })
+        .assert_user_error("can not delegate to self");
+}
+
+#[test]
+fn it_fails_to_delegate_nothing() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_user_error("nothing to delegate");
+}
+
+#[test]
+fn it_fails_to_delegate_other_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_FAKE_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_user_error("invalid payment token");
+}
+
+#[test]
+fn it_adds_delegated_weight_to_the_delegates_vote() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.owner_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegate_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+
+            let proposal = sc.proposals(proposal_id).get();
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 100), proposal.votes_for);
+        })
+        .assert_ok();
+
+    // voting again does not count the delegated weight twice
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegate_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+
+            let proposal = sc.proposals(proposal_id).get();
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 110), proposal.votes_for);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_votes_with_delegated_weight_only() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&delegate_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+
+            let proposal = sc.proposals(proposal_id).get();
+            assert_eq!(managed_biguint!(100), proposal.votes_against);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_does_not_count_delegated_weight_again_when_delegator_votes_after_delegate() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&delegate_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    // delegated weight is used already, only the payment counts
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(20), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+
+            let proposal = sc.proposals(proposal_id).get();
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 100), proposal.votes_for);
+            assert_eq!(managed_biguint!(20), proposal.votes_against);
+        })
+        .assert_ok();
+
+    // delegator without any weight left can not vote
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error("vote weight must be greater than 0");
+}
+
+#[test]
+fn it_casts_own_delegated_weight_when_delegator_votes_before_delegate() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+
+            let proposal = sc.proposals(proposal_id).get();
+            assert_eq!(managed_biguint!(100), proposal.votes_against);
+        })
+        .assert_ok();
+
+    // delegate has no weight left for this proposal
+    setup
+        .blockchain
+        .execute_tx(&delegate_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error("vote weight must be greater than 0");
+}
+
+#[test]
+fn it_undelegates_and_returns_the_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.undelegate_endpoint();
+
+            assert!(sc.delegate(&managed_address!(&delegator_address)).is_empty());
+            assert_eq!(managed_biguint!(0), sc.get_delegated_vote_weight_view(managed_address!(&delegate_address)));
+            assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&delegator_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+}
+
+#[test]
+fn it_fails_to_undelegate_while_delegated_weight_is_used_in_active_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegate_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.undelegate_endpoint();
+        })
+        .assert_user_error("delegation is locked by an active proposal");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.undelegate_endpoint();
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_undelegate_without_delegation() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.undelegate_endpoint();
+        })
+        .assert_user_error("no delegation");
+}
+
+#[test]
+fn it_delegates_the_plug_weight() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let delegator_address = setup.user_address.clone();
+    let delegate_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let proposal_id = 1;
+
+    setup.configure_plug(100, 50);
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_biguint!(100), sc.get_delegated_vote_weight_view(managed_address!(&delegate_address)));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    // 100 from the delegate's own plug weight + 100 delegated
+    setup
+        .blockchain
+        .execute_tx(&delegate_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(proposal_id).get();
+            assert_eq!(managed_biguint!(200), proposal.votes_against);
+            assert!(sc.has_user_plug_voted(proposal_id, &managed_address!(&delegator_address)));
+        })
+        .assert_ok();
+}
@@ -0,0 +1,445 @@
+use entity::config::*;
+use entity::governance::escrow::*;
+use entity::governance::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_locks_governance_tokens_in_escrow() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX);
+
+            let lock = sc.escrow_locks(&managed_address!(&user_address)).get();
+            assert_eq!(managed_biguint!(100), lock.amount);
+            assert_eq!(ESCROW_LOCK_MINUTES_MAX as u64 * 60, lock.unlock_at);
+            assert_eq!(managed_biguint!(100), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+            assert_eq!(managed_biguint!(100), sc.get_escrow_voting_power_view(managed_address!(&user_address)));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&user_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY - 100));
+}
+
+#[test]
+fn it_decays_the_voting_power_towards_the_unlock_time() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX / 2);
+
+            assert_eq!(managed_biguint!(50), sc.get_escrow_voting_power_view(managed_address!(&user_address)));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(ESCROW_LOCK_MINUTES_MAX as u64 * 60 / 4);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_biguint!(25), sc.get_escrow_voting_power_view(managed_address!(&user_address)));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(ESCROW_LOCK_MINUTES_MAX as u64 * 60 / 2);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_biguint!(0), sc.get_escrow_voting_power_view(managed_address!(&user_address)));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_lock_twice() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(60);
+        })
+        .assert_user_error("escrow already exists");
+}
+
+#[test]
+fn it_fails_to_lock_for_longer_than_the_max_lock_duration() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX + 1);
+        })
+        .assert_user_error("invalid lock duration");
+}
+
+#[test]
+fn it_fails_to_lock_other_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup.blockchain.set_esdt_balance(&user_address, b"OTHER-123456", &rust_biguint!(100));
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, b"OTHER-123456", 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(60);
+        })
+        .assert_user_error("invalid payment token");
+}
+
+#[test]
+fn it_extends_the_escrow_lock() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.extend_escrow_endpoint(120);
+
+            assert_eq!(120 * 60, sc.escrow_locks(&managed_address!(&user_address)).get().unlock_at);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.extend_escrow_endpoint(60);
+        })
+        .assert_user_error("escrow can only be extended");
+}
+
+#[test]
+fn it_increases_the_escrow_lock() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(50), |sc| {
+            sc.increase_escrow_endpoint();
+
+            let lock = sc.escrow_locks(&managed_address!(&user_address)).get();
+            assert_eq!(managed_biguint!(150), lock.amount);
+            assert_eq!(ESCROW_LOCK_MINUTES_MAX as u64 * 60, lock.unlock_at);
+            assert_eq!(managed_biguint!(150), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_increase_an_expired_escrow_lock() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(60);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(60 * 60);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(50), |sc| {
+            sc.increase_escrow_endpoint();
+        })
+        .assert_user_error("escrow has expired");
+}
+
+#[test]
+fn it_withdraws_an_expired_escrow_lock() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_escrow_endpoint();
+        })
+        .assert_user_error("escrow is still locked");
+
+    setup.blockchain.set_block_timestamp(60 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_escrow_endpoint();
+
+            assert!(sc.escrow_locks(&managed_address!(&user_address)).is_empty());
+            assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&user_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+}
+
+#[test]
+fn it_withdraws_an_escrow_lock_of_a_previous_governance_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_gov_token_endpoint(managed_token_id!(b"GOV-123456"), managed_biguint!(1_000), true);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_biguint!(0), sc.get_escrow_voting_power_view(managed_address!(&user_address)));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(60 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_escrow_endpoint();
+
+            assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&user_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+}
+
+#[test]
+fn it_guards_escrowed_tokens_from_being_spent() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.require_gov_tokens_available(&managed_biguint!(1), 0);
+        })
+        .assert_user_error("not enough governance tokens available");
+}
+
+#[test]
+fn it_adds_the_escrow_voting_power_when_voting() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+
+            assert_eq!(managed_biguint!(110), sc.proposals(proposal_id).get().votes_against);
+        })
+        .assert_ok();
+
+    // escrow power is only counted once per proposal
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error("vote weight must be greater than 0");
+}
+
+#[test]
+fn it_adds_the_escrow_voting_power_when_proposing() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+
+            assert_eq!(managed_biguint!(100), sc.proposals(proposal_id).get().votes_for);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_withdraw_while_a_voted_proposal_is_active() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup.blockchain.set_esdt_balance(&voter_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(1_000_000));
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(1_000_000), |sc| {
+            sc.lock_escrow_endpoint(VOTING_PERIOD_MINUTES_DEFAULT / 2);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 / 2 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_escrow_endpoint();
+        })
+        .assert_user_error("escrow is locked by an active proposal");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_escrow_endpoint();
+        })
+        .assert_ok();
+}
@@ -0,0 +1,332 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_returns_expired_when_the_grace_period_has_passed() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    setup.configure_leaderless();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_grace_period_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                sc.calculate_actions_hash(&ManagedVec::new()),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    let voting_period_end = VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60;
+
+    setup.blockchain.set_block_timestamp(voting_period_end + 60 * 60 - 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(voting_period_end + 60 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Expired, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_starts_the_grace_period_after_the_execution_delay() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    setup.configure_leaderless();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_endpoint(60);
+            sc.change_grace_period_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                sc.calculate_actions_hash(&ManagedVec::new()),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    let voting_period_end = VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60;
+
+    setup.blockchain.set_block_timestamp(voting_period_end + 60 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(voting_period_end + 2 * 60 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Expired, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_never_expires_when_no_grace_period_is_configured() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    setup.configure_leaderless();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                sc.calculate_actions_hash(&ManagedVec::new()),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 * 100);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_neither_queues_nor_expires_a_proposal_without_actions() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_endpoint(60);
+            sc.change_grace_period_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    let voting_period_end = VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60;
+
+    setup.blockchain.set_block_timestamp(voting_period_end);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(voting_period_end + 2 * 60 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_execute_an_expired_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup.blockchain.set_egld_balance(setup.contract.address_ref(), &rust_biguint!(1000));
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
+            sc.create_permission(
+                managed_buffer!(b"perm"),
+                managed_biguint!(5),
+                managed_address!(&action_receiver),
+                managed_buffer!(b"myendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy(
+                managed_buffer!(ROLE_BUILTIN_LEADER),
+                managed_buffer!(b"perm"),
+                PolicyMethod::Quorum,
+                BigUint::from(1u64),
+                10,
+            );
+            sc.grace_period_in_minutes().set(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(5),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                managed_buffer!(b"a"),
+                managed_buffer!(b"b"),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                actions_permissions,
+            );
+        })
+        .assert_ok();
+
+    // policy voting period of 10 minutes plus grace period of 60 minutes
+    setup.blockchain.set_block_timestamp(70 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(5),
+                payments: ManagedVec::new(),
+            });
+
+            sc.execute_endpoint(proposal_id, MultiValueManagedVec::from(actions));
+        })
+        .assert_user_error("proposal has expired");
+
+    setup.blockchain.check_egld_balance(&action_receiver, &rust_biguint!(0));
+}
+
+#[test]
+fn it_allows_voters_to_withdraw_from_an_expired_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    setup.configure_leaderless();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_grace_period_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                sc.calculate_actions_hash(&ManagedVec::new()),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(20), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 60 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            assert_eq!(ProposalStatus::Expired, sc.get_proposal_status_view(proposal_id));
+
+            sc.withdraw_endpoint();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&voter_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+}
@@ -0,0 +1,358 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_queues_a_signer_majority_proposal_until_the_execution_delay_has_passed() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup.blockchain.set_egld_balance(setup.contract.address_ref(), &rust_biguint!(1000));
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
+            sc.create_permission(
+                managed_buffer!(b"perm"),
+                managed_biguint!(5),
+                managed_address!(&action_receiver),
+                managed_buffer!(b"myendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy(
+                managed_buffer!(ROLE_BUILTIN_LEADER),
+                managed_buffer!(b"perm"),
+                PolicyMethod::Quorum,
+                BigUint::from(1u64),
+                10,
+            );
+            sc.execution_delay_in_minutes().set(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(5),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                managed_buffer!(b"a"),
+                managed_buffer!(b"b"),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                actions_permissions,
+            );
+
+            // succeeded early via signer majority, so the delay starts right away
+            assert_eq!(60 * 60, sc.proposals(proposal_id).get().executable_after);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Queued, sc.get_proposal_status_view(proposal_id));
+            assert_eq!(60 * 60, sc.get_proposal_execution_delay_remaining_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(60 * 60 - 1);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(5),
+                payments: ManagedVec::new(),
+            });
+
+            sc.execute_endpoint(proposal_id, MultiValueManagedVec::from(actions));
+        })
+        .assert_user_error("proposal is queued");
+
+    setup.blockchain.set_block_timestamp(60 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(5),
+                payments: ManagedVec::new(),
+            });
+
+            assert_eq!(0, sc.get_proposal_execution_delay_remaining_view(proposal_id));
+
+            sc.execute_endpoint(proposal_id, MultiValueManagedVec::from(actions));
+        })
+        .assert_ok();
+
+    setup.blockchain.check_egld_balance(&action_receiver, &rust_biguint!(5));
+}
+
+#[test]
+fn it_queues_a_token_weighted_proposal_after_the_voting_period_has_ended() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    setup.configure_leaderless();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                sc.calculate_actions_hash(&ManagedVec::new()),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+
+            let proposal = sc.proposals(proposal_id).get();
+            assert_eq!(proposal.ends_at + 60 * 60, proposal.executable_after);
+        })
+        .assert_ok();
+
+    let voting_period_end = VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60;
+
+    setup.blockchain.set_block_timestamp(voting_period_end + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Queued, sc.get_proposal_status_view(proposal_id));
+            assert_eq!(60 * 60 - 1, sc.get_proposal_execution_delay_remaining_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(voting_period_end + 60 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_does_not_queue_a_defeated_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Defeated, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_applies_the_longest_execution_delay_of_the_entity_and_policies() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let sc_address = setup.contract.address_ref().clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&sc_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
+            sc.create_permission(
+                managed_buffer!(b"perm"),
+                managed_biguint!(5),
+                managed_address!(&action_receiver),
+                managed_buffer!(b"myendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy(
+                managed_buffer!(ROLE_BUILTIN_LEADER),
+                managed_buffer!(b"perm"),
+                PolicyMethod::Quorum,
+                BigUint::from(1u64),
+                10,
+            );
+            sc.change_execution_delay_endpoint(60);
+            sc.change_policy_execution_delay_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(b"perm"), 120);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(5),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                managed_buffer!(b"a"),
+                managed_buffer!(b"b"),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                actions_permissions,
+            );
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(60 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Queued, sc.get_proposal_status_view(proposal_id));
+            assert_eq!(60 * 60, sc.get_proposal_execution_delay_remaining_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_allows_voters_to_withdraw_while_queued() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    setup.configure_leaderless();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                sc.calculate_actions_hash(&ManagedVec::new()),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(20), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            assert_eq!(ProposalStatus::Queued, sc.get_proposal_status_view(proposal_id));
+
+            sc.withdraw_endpoint();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&voter_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+}
+
+#[test]
+fn it_fails_to_change_the_policy_execution_delay_when_the_policy_does_not_exist() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_policy_execution_delay_endpoint(managed_buffer!(ROLE_BUILTIN_LEADER), managed_buffer!(b"perm"), 120);
+        })
+        .assert_user_error("policy does not exist");
+}
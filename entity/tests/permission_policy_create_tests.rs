@@ -0,0 +1,134 @@
+use entity::config::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_creates_a_weighted_policy_with_a_pass_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let sc_address = setup.contract.address_ref().clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+
+            sc.create_policy_weighted_endpoint(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                managed_biguint!(QURUM),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+                OptionalValue::Some(75),
+            );
+
+            let policy = sc.policies(&managed_buffer!(b"testrole")).get(&managed_buffer!(b"testperm")).unwrap();
+            assert_eq!(PolicyMethod::Weight, policy.method);
+            assert_eq!(75, policy.pass_threshold);
+
+            let (permission, method, quorum, voting_period, pass_threshold, execution_delay) =
+                sc.get_policies_view(managed_buffer!(b"testrole")).into_iter().next().unwrap().into_tuple();
+            assert_eq!(managed_buffer!(b"testperm"), permission);
+            assert_eq!(managed_buffer!(b"weight"), method);
+            assert_eq!(managed_biguint!(QURUM), quorum);
+            assert_eq!(VOTING_PERIOD_MINUTES_DEFAULT, voting_period);
+            assert_eq!(75, pass_threshold);
+            assert_eq!(0, execution_delay);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_falls_back_to_the_entity_pass_threshold_when_not_provided() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let sc_address = setup.contract.address_ref().clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+
+            sc.create_policy_weighted_endpoint(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                managed_biguint!(QURUM),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+                OptionalValue::None,
+            );
+
+            let policy = sc.policies(&managed_buffer!(b"testrole")).get(&managed_buffer!(b"testperm")).unwrap();
+            assert_eq!(0, policy.pass_threshold);
+            assert_eq!(PASS_THRESHOLD_PERCENT_DEFAULT, sc.get_policy_pass_threshold(&policy));
+
+            sc.change_pass_threshold_endpoint(66);
+            assert_eq!(66, sc.get_policy_pass_threshold(&policy));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_create_a_weighted_policy_with_an_invalid_pass_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let sc_address = setup.contract.address_ref().clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+
+            sc.create_policy_weighted_endpoint(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                managed_biguint!(QURUM),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+                OptionalValue::Some(40),
+            );
+        })
+        .assert_user_error("invalid pass threshold");
+}
+
+#[test]
+fn it_must_call_itself() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_policy_weighted_endpoint(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                managed_biguint!(QURUM),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,257 @@
+use entity::config::*;
+use entity::governance::poll::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::multiversx_chain_vm::tx_mock::TxResult;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+fn create_action(receiver: &Address) -> Action<DebugApi> {
+    Action::<DebugApi> {
+        destination: managed_address!(receiver),
+        endpoint: managed_buffer!(b"myendpoint"),
+        arguments: ManagedVec::new(),
+        gas_limit: 5_000_000u64,
+        value: managed_biguint!(5),
+        payments: ManagedVec::new(),
+    }
+}
+
+fn setup_permissions(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, receivers: Vec<&Address>) {
+    let proposer_address = setup.user_address.clone();
+
+    setup.blockchain.set_egld_balance(setup.contract.address_ref(), &rust_biguint!(1000));
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
+
+            for (index, receiver) in receivers.iter().enumerate() {
+                let permission = managed_buffer!(format!("perm{}", index + 1).as_bytes());
+
+                sc.create_permission(
+                    permission.clone(),
+                    managed_biguint!(5),
+                    managed_address!(receiver),
+                    managed_buffer!(b"myendpoint"),
+                    ManagedVec::new(),
+                    ManagedVec::new(),
+                );
+                sc.create_policy(managed_buffer!(ROLE_BUILTIN_LEADER), permission, PolicyMethod::Quorum, BigUint::from(1u64), 10);
+            }
+        })
+        .assert_ok();
+}
+
+fn propose_poll_with_actions(
+    setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>,
+    receivers: Vec<&Address>,
+    permissions: Vec<&[u8]>,
+) -> (TxResult, u64) {
+    let proposer_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    let result = setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let option_actions_hashes = receivers
+                .iter()
+                .map(|receiver| sc.calculate_actions_hash(&ManagedVec::from(vec![create_action(receiver)])))
+                .collect::<Vec<_>>();
+            let permissions = permissions.iter().map(|perm| managed_buffer!(perm)).collect::<Vec<_>>();
+
+            proposal_id = sc.propose_poll_endpoint(
+                managed_buffer!(b"id"),
+                managed_buffer!(b"content"),
+                ManagedBuffer::new(),
+                PollMode::Single,
+                receivers.len() as u8,
+                ManagedVec::new(),
+                ManagedVec::from(option_actions_hashes),
+                MultiValueManagedVec::from(permissions),
+            );
+        });
+
+    (result, proposal_id)
+}
+
+fn vote_poll(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, voter: &Address, proposal_id: u64, weight: u64, options: Vec<u8>) -> TxResult {
+    setup
+        .blockchain
+        .execute_esdt_transfer(voter, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(weight), |sc| {
+            sc.vote_poll_endpoint(proposal_id, MultiValueManagedVec::from(options.clone()));
+        })
+}
+
+fn execute(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, proposal_id: u64, receiver: &Address) -> TxResult {
+    let proposer_address = setup.user_address.clone();
+
+    setup.blockchain.execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+        sc.execute_endpoint(proposal_id, MultiValueManagedVec::from(vec![create_action(receiver)]));
+    })
+}
+
+#[test]
+fn it_executes_the_actions_of_the_winning_option() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let user_address = setup.user_address.clone();
+    let receiver_one = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let receiver_two = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+    setup_permissions(&mut setup, vec![&receiver_one, &receiver_two]);
+
+    let (result, proposal_id) = propose_poll_with_actions(&mut setup, vec![&receiver_one, &receiver_two], vec![b"perm1", b"perm2"]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &user_address, proposal_id, QURUM, vec![2]).assert_ok();
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1]).assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    execute(&mut setup, proposal_id, &receiver_two).assert_ok();
+
+    setup.blockchain.check_egld_balance(&receiver_one, &rust_biguint!(0));
+    setup.blockchain.check_egld_balance(&receiver_two, &rust_biguint!(5));
+}
+
+#[test]
+fn it_fails_to_execute_the_actions_of_a_losing_option() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let user_address = setup.user_address.clone();
+    let receiver_one = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let receiver_two = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+    setup_permissions(&mut setup, vec![&receiver_one, &receiver_two]);
+
+    let (result, proposal_id) = propose_poll_with_actions(&mut setup, vec![&receiver_one, &receiver_two], vec![b"perm1", b"perm2"]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &user_address, proposal_id, QURUM, vec![2]).assert_ok();
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1]).assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    execute(&mut setup, proposal_id, &receiver_one).assert_user_error("actions have been corrupted");
+
+    setup.blockchain.check_egld_balance(&receiver_one, &rust_biguint!(0));
+}
+
+#[test]
+fn it_fails_to_execute_while_poll_voting_is_ongoing() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+    let receiver_one = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let receiver_two = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+    setup_permissions(&mut setup, vec![&receiver_one, &receiver_two]);
+
+    let (result, proposal_id) = propose_poll_with_actions(&mut setup, vec![&receiver_one, &receiver_two], vec![b"perm1", b"perm2"]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &user_address, proposal_id, QURUM, vec![2]).assert_ok();
+
+    execute(&mut setup, proposal_id, &receiver_two).assert_user_error("poll voting has not ended");
+}
+
+#[test]
+fn it_fails_to_execute_when_the_poll_has_no_winner() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let user_address = setup.user_address.clone();
+    let receiver_one = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let receiver_two = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+    setup_permissions(&mut setup, vec![&receiver_one, &receiver_two]);
+
+    let (result, proposal_id) = propose_poll_with_actions(&mut setup, vec![&receiver_one, &receiver_two], vec![b"perm1", b"perm2"]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &user_address, proposal_id, QURUM, vec![2]).assert_ok();
+    vote_poll(&mut setup, &owner_address, proposal_id, QURUM + MIN_PROPOSE_WEIGHT, vec![1]).assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    execute(&mut setup, proposal_id, &receiver_two).assert_user_error("poll has no winner");
+}
+
+#[test]
+fn it_fails_to_execute_when_the_winning_option_requires_unannounced_permissions() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let user_address = setup.user_address.clone();
+    let receiver_one = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let receiver_two = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+    setup_permissions(&mut setup, vec![&receiver_one, &receiver_two]);
+
+    let (result, proposal_id) = propose_poll_with_actions(&mut setup, vec![&receiver_one, &receiver_two], vec![b"perm1"]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &user_address, proposal_id, QURUM, vec![2]).assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    execute(&mut setup, proposal_id, &receiver_two).assert_user_error("untruthful permissions announced");
+}
+
+#[test]
+fn it_fails_to_create_a_poll_with_a_mismatching_amount_of_option_actions_hashes() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(vec![create_action(&receiver)]));
+
+            sc.propose_poll_endpoint(
+                managed_buffer!(b"id"),
+                managed_buffer!(b"content"),
+                ManagedBuffer::new(),
+                PollMode::Single,
+                3,
+                ManagedVec::new(),
+                ManagedVec::from(vec![actions_hash.clone(), actions_hash]),
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_user_error("invalid poll option actions hashes");
+}
+
+#[test]
+fn it_fails_to_create_a_poll_with_an_invalid_option_actions_hash() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            sc.propose_poll_endpoint(
+                managed_buffer!(b"id"),
+                managed_buffer!(b"content"),
+                ManagedBuffer::new(),
+                PollMode::Single,
+                2,
+                ManagedVec::new(),
+                ManagedVec::from(vec![managed_buffer!(b"a"), managed_buffer!(b"b")]),
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_user_error("invalid actions hash");
+}
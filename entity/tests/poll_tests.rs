@@ -0,0 +1,417 @@
+use entity::config::*;
+use entity::governance::poll::*;
+use entity::governance::*;
+use entity::governance::proposal::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::multiversx_chain_vm::tx_mock::TxResult;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+fn propose_poll(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, mode: PollMode, option_count: u8, option_hashes: Vec<&[u8]>) -> (TxResult, u64) {
+    let proposer_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    let result = setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let option_hashes = option_hashes.iter().map(|hash| managed_buffer!(hash)).collect::<Vec<_>>();
+
+            proposal_id = sc.propose_poll_endpoint(
+                managed_buffer!(b"id"),
+                managed_buffer!(b"content"),
+                ManagedBuffer::new(),
+                mode,
+                option_count,
+                ManagedVec::from(option_hashes),
+                ManagedVec::new(),
+                MultiValueManagedVec::new(),
+            );
+        });
+
+    (result, proposal_id)
+}
+
+fn vote_poll(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, voter: &Address, proposal_id: u64, weight: u64, options: Vec<u8>) -> TxResult {
+    setup
+        .blockchain
+        .execute_esdt_transfer(voter, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(weight), |sc| {
+            sc.vote_poll_endpoint(proposal_id, MultiValueManagedVec::from(options.clone()));
+        })
+}
+
+fn assert_poll_results(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, proposal_id: u64, expected: Vec<u64>) {
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let results = sc.get_proposal_poll_results_view(proposal_id).to_vec();
+
+            assert_eq!(expected.len(), results.len());
+
+            for (index, weight) in expected.iter().enumerate() {
+                assert_eq!(managed_biguint!(*weight), results.get(index).clone_value());
+            }
+        })
+        .assert_ok();
+}
+
+fn assert_poll_winner(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, proposal_id: u64, expected: u8) {
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(expected, sc.get_poll_winner_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_creates_a_poll_with_declared_options() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Approval, 3, vec![b"a", b"b", b"c"]);
+    result.assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let poll = sc.polls(proposal_id).get();
+
+            assert_eq!(PollMode::Approval, poll.mode);
+            assert_eq!(3, poll.option_count);
+            assert_eq!(managed_buffer!(b"b"), poll.option_hashes.get(1).clone_value());
+        })
+        .assert_ok();
+
+    assert_poll_results(&mut setup, proposal_id, vec![0, 0, 0]);
+}
+
+#[test]
+fn it_fails_to_create_a_poll_with_less_than_two_options() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+
+    let (result, _) = propose_poll(&mut setup, PollMode::Single, 1, vec![]);
+
+    result.assert_user_error("invalid poll option count");
+}
+
+#[test]
+fn it_fails_to_create_a_poll_with_more_than_max_options() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+
+    let (result, _) = propose_poll(&mut setup, PollMode::Single, POLL_MAX_OPTIONS + 1, vec![]);
+
+    result.assert_user_error("invalid poll option count");
+}
+
+#[test]
+fn it_fails_to_create_a_poll_with_a_mismatching_amount_of_option_hashes() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+
+    let (result, _) = propose_poll(&mut setup, PollMode::Single, 3, vec![b"a", b"b"]);
+
+    result.assert_user_error("invalid poll option hashes");
+}
+
+#[test]
+fn it_casts_a_single_choice_ballot() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    let owner_address = setup.owner_address.clone();
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Single, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![2]).assert_ok();
+
+    assert_poll_results(&mut setup, proposal_id, vec![0, 10, 0]);
+    assert_poll_winner(&mut setup, proposal_id, 2);
+}
+
+#[test]
+fn it_casts_the_proposers_ballot_with_the_weight_proposed_with() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    let proposer_address = setup.user_address.clone();
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Single, 3, vec![]);
+    result.assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.vote_poll_endpoint(proposal_id, MultiValueManagedVec::from(vec![3u8]));
+        })
+        .assert_ok();
+
+    assert_poll_results(&mut setup, proposal_id, vec![0, 0, MIN_PROPOSE_WEIGHT]);
+}
+
+#[test]
+fn it_fails_to_select_multiple_options_in_a_single_choice_poll() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    let owner_address = setup.owner_address.clone();
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Single, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1, 2]).assert_user_error("poll allows a single option only");
+}
+
+#[test]
+fn it_fails_to_select_an_out_of_range_option() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    let owner_address = setup.owner_address.clone();
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Approval, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1, 4]).assert_user_error("invalid poll option");
+}
+
+#[test]
+fn it_fails_to_select_an_out_of_range_option_via_vote_for() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Single, 3, vec![]);
+    result.assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::Some(4));
+        })
+        .assert_user_error("invalid poll option");
+}
+
+#[test]
+fn it_fails_to_select_a_single_option_via_vote_for_in_a_ballot_poll() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Ranked, 3, vec![]);
+    result.assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::Some(1));
+        })
+        .assert_user_error("poll requires a ballot");
+}
+
+#[test]
+fn it_fails_to_select_an_option_above_max_on_proposals_without_a_poll() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
+            sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_MAX_OPTIONS + 1,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_user_error("invalid poll option");
+}
+
+#[test]
+fn it_fails_to_select_duplicate_options() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    let owner_address = setup.owner_address.clone();
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Approval, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1, 1]).assert_user_error("duplicate poll option");
+}
+
+#[test]
+fn it_fails_to_cast_a_second_ballot() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    let owner_address = setup.owner_address.clone();
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Approval, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1]).assert_ok();
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![2]).assert_user_error("poll ballot already cast");
+}
+
+#[test]
+fn it_adds_the_full_weight_to_each_approved_option() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    let owner_address = setup.owner_address.clone();
+    let voter = setup.blockchain.create_user_account(&rust_biguint!(0));
+    setup.blockchain.set_esdt_balance(&voter, ENTITY_GOV_TOKEN_ID, &rust_biguint!(100));
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Approval, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1, 3]).assert_ok();
+    vote_poll(&mut setup, &voter, proposal_id, 8, vec![3]).assert_ok();
+
+    assert_poll_results(&mut setup, proposal_id, vec![10, 0, 18]);
+    assert_poll_winner(&mut setup, proposal_id, 3);
+}
+
+#[test]
+fn it_removes_the_ballot_when_revoking_the_vote() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(false);
+    let owner_address = setup.owner_address.clone();
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Approval, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1, 2]).assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.revoke_vote_endpoint(proposal_id);
+        })
+        .assert_ok();
+
+    assert_poll_results(&mut setup, proposal_id, vec![0, 0, 0]);
+    assert_poll_winner(&mut setup, proposal_id, 0);
+}
+
+#[test]
+fn it_removes_the_ballot_when_changing_the_vote_away_from_for() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(false);
+    let owner_address = setup.owner_address.clone();
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Approval, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1, 2]).assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_endpoint(proposal_id, VoteType::Against);
+        })
+        .assert_ok();
+
+    assert_poll_results(&mut setup, proposal_id, vec![0, 0, 0]);
+    assert_poll_winner(&mut setup, proposal_id, 0);
+}
+
+#[test]
+fn it_returns_no_winner_on_a_tie() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    let owner_address = setup.owner_address.clone();
+    let voter = setup.blockchain.create_user_account(&rust_biguint!(0));
+    setup.blockchain.set_esdt_balance(&voter, ENTITY_GOV_TOKEN_ID, &rust_biguint!(100));
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Single, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1]).assert_ok();
+    vote_poll(&mut setup, &voter, proposal_id, 10, vec![2]).assert_ok();
+
+    assert_poll_winner(&mut setup, proposal_id, 0);
+}
+
+#[test]
+fn it_determines_the_ranked_choice_winner_by_instant_runoff() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    let voter_one = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let voter_two = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let voter_three = setup.blockchain.create_user_account(&rust_biguint!(0));
+    setup.blockchain.set_esdt_balance(&voter_one, ENTITY_GOV_TOKEN_ID, &rust_biguint!(100));
+    setup.blockchain.set_esdt_balance(&voter_two, ENTITY_GOV_TOKEN_ID, &rust_biguint!(100));
+    setup.blockchain.set_esdt_balance(&voter_three, ENTITY_GOV_TOKEN_ID, &rust_biguint!(100));
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Ranked, 3, vec![]);
+    result.assert_ok();
+
+    // first preferences: 1 => 40, 2 => 35, 3 => 25. option 3 is eliminated and its ballot moves to option 2.
+    vote_poll(&mut setup, &voter_one, proposal_id, 40, vec![1, 2]).assert_ok();
+    vote_poll(&mut setup, &voter_two, proposal_id, 35, vec![2, 1]).assert_ok();
+    vote_poll(&mut setup, &voter_three, proposal_id, 25, vec![3, 2]).assert_ok();
+
+    assert_poll_results(&mut setup, proposal_id, vec![40, 35, 25]);
+    assert_poll_winner(&mut setup, proposal_id, 2);
+}
+
+#[test]
+fn it_determines_the_ranked_choice_winner_by_first_preference_majority() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    let owner_address = setup.owner_address.clone();
+    let voter = setup.blockchain.create_user_account(&rust_biguint!(0));
+    setup.blockchain.set_esdt_balance(&voter, ENTITY_GOV_TOKEN_ID, &rust_biguint!(100));
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Ranked, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 60, vec![3, 1]).assert_ok();
+    vote_poll(&mut setup, &voter, proposal_id, 40, vec![1, 2, 3]).assert_ok();
+
+    assert_poll_winner(&mut setup, proposal_id, 3);
+}
+
+#[test]
+fn it_adds_weight_voted_after_the_ballot_to_its_options() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(false);
+    let owner_address = setup.owner_address.clone();
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Approval, 3, vec![]);
+    result.assert_ok();
+
+    vote_poll(&mut setup, &owner_address, proposal_id, 10, vec![1, 2]).assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(5), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    assert_poll_results(&mut setup, proposal_id, vec![15, 15, 0]);
+}
+
+#[test]
+fn it_fails_to_cast_more_than_the_max_ranked_poll_ballots() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(false);
+
+    let (result, proposal_id) = propose_poll(&mut setup, PollMode::Ranked, 3, vec![]);
+    result.assert_ok();
+
+    for _ in 0..POLL_MAX_RANKED_BALLOTS {
+        let voter_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+        setup.blockchain.set_esdt_balance(&voter_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(1));
+
+        vote_poll(&mut setup, &voter_address, proposal_id, 1, vec![1, 2]).assert_ok();
+    }
+
+    let voter_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    setup.blockchain.set_esdt_balance(&voter_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(1));
+
+    vote_poll(&mut setup, &voter_address, proposal_id, 1, vec![1, 2]).assert_user_error("max ranked poll ballots reached");
+
+    assert_poll_winner(&mut setup, proposal_id, 1);
+}
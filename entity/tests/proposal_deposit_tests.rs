@@ -0,0 +1,394 @@
+use entity::config::*;
+use entity::governance::deposit::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const DEPOSIT: u64 = 10;
+
+fn configure_deposit(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, token_id: &[u8], burn_on_slash: bool) {
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let token_id = match token_id {
+                b"EGLD" => EgldOrEsdtTokenIdentifier::egld(),
+                _ => EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(token_id)),
+            };
+
+            sc.set_proposal_deposit_endpoint(token_id, managed_biguint!(DEPOSIT), burn_on_slash);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_deducts_the_deposit_from_the_proposal_payments() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_deposit(&mut setup, ENTITY_GOV_TOKEN_ID, false);
+
+    let proposal_id = setup.propose(&proposer_address, DEPOSIT + MIN_PROPOSE_WEIGHT, b"id");
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let deposit = sc.proposal_deposits(proposal_id).get();
+
+            assert_eq!(managed_biguint!(DEPOSIT), deposit.amount);
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), sc.proposals(proposal_id).get().votes_for);
+            assert_eq!(
+                managed_biguint!(DEPOSIT + MIN_PROPOSE_WEIGHT),
+                sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get()
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_propose_without_the_deposit() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_deposit(&mut setup, ENTITY_GOV_TOKEN_ID, false);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(DEPOSIT - 1), |sc| {
+            sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_user_error("proposal deposit required");
+}
+
+#[test]
+fn it_refunds_the_deposit_when_the_proposal_reached_quorum() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_deposit(&mut setup, ENTITY_GOV_TOKEN_ID, false);
+
+    let proposal_id = setup.propose(&proposer_address, DEPOSIT + QURUM, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_endpoint();
+
+            assert!(sc.proposal_deposits(proposal_id).is_empty());
+            assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&proposer_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+}
+
+#[test]
+fn it_keeps_the_deposit_in_the_treasury_when_the_proposal_missed_quorum() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_deposit(&mut setup, ENTITY_GOV_TOKEN_ID, false);
+
+    let proposal_id = setup.propose(&proposer_address, DEPOSIT + MIN_PROPOSE_WEIGHT, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    // withdrawable by anyone on behalf of the proposer
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_all_endpoint(proposal_id);
+
+            assert!(sc.proposal_deposits(proposal_id).is_empty());
+            assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&proposer_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY - DEPOSIT));
+
+    setup
+        .blockchain
+        .check_esdt_balance(setup.contract.address_ref(), ENTITY_GOV_TOKEN_ID, &rust_biguint!(DEPOSIT));
+}
+
+#[test]
+fn it_settles_the_deposit_after_the_proposer_revoked_their_vote() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_deposit(&mut setup, ENTITY_GOV_TOKEN_ID, false);
+
+    let proposal_id = setup.propose(&proposer_address, DEPOSIT + MIN_PROPOSE_WEIGHT, b"id");
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.revoke_vote_endpoint(proposal_id);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&proposer_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY - DEPOSIT));
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_endpoint();
+
+            assert!(sc.proposal_deposits(proposal_id).is_empty());
+            assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(ENTITY_GOV_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(setup.contract.address_ref(), ENTITY_GOV_TOKEN_ID, &rust_biguint!(DEPOSIT));
+}
+
+#[test]
+fn it_burns_the_deposit_when_configured() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_deposit(&mut setup, ENTITY_GOV_TOKEN_ID, true);
+
+    setup
+        .blockchain
+        .set_esdt_local_roles(setup.contract.address_ref(), ENTITY_GOV_TOKEN_ID, &[EsdtLocalRole::Burn]);
+
+    setup.propose(&proposer_address, DEPOSIT + MIN_PROPOSE_WEIGHT, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_endpoint();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&proposer_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY - DEPOSIT));
+
+    setup
+        .blockchain
+        .check_esdt_balance(setup.contract.address_ref(), ENTITY_GOV_TOKEN_ID, &rust_biguint!(0));
+}
+
+#[test]
+fn it_slashes_the_deposit_of_proposals_flagged_as_spam() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let guardian_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_deposit(&mut setup, ENTITY_GOV_TOKEN_ID, false);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"guardian"), managed_address!(&guardian_address));
+            sc.set_guardian_role_endpoint(managed_buffer!(b"guardian"));
+        })
+        .assert_ok();
+
+    let proposal_id = setup.propose(&proposer_address, DEPOSIT + QURUM, b"id");
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.flag_spam_endpoint(proposal_id);
+        })
+        .assert_user_error("caller must be guardian");
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.flag_spam_endpoint(proposal_id);
+
+            assert!(sc.proposal_spam_flags(proposal_id).get());
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+
+            sc.withdraw_endpoint();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&proposer_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY - DEPOSIT));
+}
+
+#[test]
+fn it_fails_to_flag_an_executed_proposal_as_spam() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let guardian_address = setup.owner_address.clone();
+    let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    setup.configure_leaderless();
+    configure_deposit(&mut setup, ENTITY_GOV_TOKEN_ID, false);
+
+    setup.blockchain.set_egld_balance(setup.contract.address_ref(), &rust_biguint!(1000));
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(ROLE_BUILTIN_GUARDIAN), managed_address!(&guardian_address));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(DEPOSIT + QURUM), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(5),
+                payments: ManagedVec::new(),
+            });
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                sc.calculate_actions_hash(&ManagedVec::from(actions)),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(5),
+                payments: ManagedVec::new(),
+            });
+
+            sc.execute_endpoint(proposal_id, MultiValueManagedVec::from(actions));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.flag_spam_endpoint(proposal_id);
+        })
+        .assert_user_error("proposal can not be flagged");
+}
+
+#[test]
+fn it_guards_egld_deposits_against_treasury_spending() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.owner_address.clone();
+    let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    configure_deposit(&mut setup, b"EGLD", false);
+
+    setup.blockchain.set_egld_balance(&proposer_address, &rust_biguint!(DEPOSIT));
+    setup.blockchain.set_egld_balance(setup.contract.address_ref(), &rust_biguint!(0));
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(DEPOSIT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+
+            assert_eq!(managed_biguint!(DEPOSIT), sc.guarded_egld_deposits().get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: ManagedBuffer::new(),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(DEPOSIT),
+                payments: ManagedVec::new(),
+            });
+
+            sc.direct_execute_endpoint(MultiValueManagedVec::from(actions));
+        })
+        .assert_user_error("not enough egld available");
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_endpoint();
+        })
+        .assert_ok();
+
+    setup.blockchain.check_egld_balance(&proposer_address, &rust_biguint!(DEPOSIT));
+}
@@ -0,0 +1,229 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const VOTING_PERIOD_SECONDS: u64 = VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60;
+
+fn configure_dynamic_quorum(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, min: u64, max: u64, sample_size: usize) {
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_dynamic_quorum_endpoint(managed_biguint!(min), managed_biguint!(max), sample_size);
+        })
+        .assert_ok();
+}
+
+fn assert_effective_quorum(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, expected: u64) {
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_biguint!(expected), sc.get_effective_quorum_view());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_uses_the_configured_quorum_until_proposals_have_finished() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_dynamic_quorum(&mut setup, 10, 200, 2);
+
+    assert_effective_quorum(&mut setup, QURUM);
+
+    setup.propose(&proposer_address, 100, b"id1");
+
+    assert_effective_quorum(&mut setup, QURUM);
+}
+
+#[test]
+fn it_follows_the_average_turnout_of_finished_proposals() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_dynamic_quorum(&mut setup, 10, 200, 2);
+
+    setup.propose(&proposer_address, 100, b"id1");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS);
+
+    assert_effective_quorum(&mut setup, 100);
+
+    setup.propose(&proposer_address, 60, b"id2");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS * 2);
+
+    assert_effective_quorum(&mut setup, 80);
+}
+
+#[test]
+fn it_only_averages_the_most_recent_sample_size_proposals() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_dynamic_quorum(&mut setup, 10, 200, 2);
+
+    setup.propose(&proposer_address, 150, b"id1");
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS);
+
+    setup.propose(&proposer_address, 60, b"id2");
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS * 2);
+
+    setup.propose(&proposer_address, 40, b"id3");
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS * 3);
+
+    assert_effective_quorum(&mut setup, 50);
+}
+
+#[test]
+fn it_bounds_the_effective_quorum() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_dynamic_quorum(&mut setup, 10, 80, 1);
+
+    setup.propose(&proposer_address, 100, b"id1");
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS);
+
+    assert_effective_quorum(&mut setup, 80);
+
+    setup.propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"id2");
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS * 2);
+
+    assert_effective_quorum(&mut setup, 10);
+}
+
+#[test]
+fn it_excludes_canceled_proposals_from_the_average_turnout() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_dynamic_quorum(&mut setup, 10, 200, 2);
+
+    let proposal_id = setup.propose(&proposer_address, 100, b"id1");
+
+    setup
+        .blockchain
+        .execute_tx(&setup.user_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.cancel_proposal_endpoint(proposal_id);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS);
+
+    assert_effective_quorum(&mut setup, QURUM);
+}
+
+#[test]
+fn it_stores_the_effective_quorum_on_the_proposal_at_creation() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_dynamic_quorum(&mut setup, 10, 200, 1);
+
+    setup.propose(&proposer_address, 100, b"id1");
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS);
+
+    let proposal_id = setup.propose(&proposer_address, 90, b"id2");
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_biguint!(100), sc.proposals(proposal_id).get().snapshot.unwrap().quorum);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS * 2);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Defeated, sc.get_proposal_status_view(proposal_id));
+            assert_eq!(managed_biguint!(90), sc.get_effective_quorum_view());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_does_not_change_the_quorum_of_existing_proposals() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_dynamic_quorum(&mut setup, 10, 200, 1);
+
+    let proposal_id = setup.propose(&proposer_address, QURUM, b"id1");
+
+    setup.propose(&proposer_address, 150, b"id2");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+            assert_eq!(managed_biguint!(150), sc.get_effective_quorum_view());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_averages_the_most_recently_ended_proposals_regardless_of_recording_order() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_dynamic_quorum(&mut setup, 10, 200, 1);
+
+    setup.propose(&proposer_address, 100, b"id1");
+    setup.blockchain.set_block_timestamp(1);
+    setup.propose(&proposer_address, 60, b"id2");
+    setup.blockchain.set_block_timestamp(2);
+    setup.propose(&proposer_address, 40, b"id3");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS);
+    setup.propose(&proposer_address, 20, b"id4");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS + 1);
+    setup.propose(&proposer_address, 80, b"id5");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS * 2 + 1);
+
+    assert_effective_quorum(&mut setup, 80);
+
+    setup.propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"id6");
+
+    assert_effective_quorum(&mut setup, 80);
+}
+
+#[test]
+fn it_clears_the_turnout_history_when_the_dynamic_quorum_is_removed() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_dynamic_quorum(&mut setup, 10, 200, 2);
+
+    setup.propose(&proposer_address, 100, b"id1");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS);
+
+    // records the turnout of the first proposal in the history
+    setup.propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"id2");
+
+    assert_effective_quorum(&mut setup, 100);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.remove_dynamic_quorum_endpoint();
+
+            assert!(sc.proposal_turnouts().is_empty());
+        })
+        .assert_ok();
+
+    configure_dynamic_quorum(&mut setup, 10, 200, 2);
+
+    assert_effective_quorum(&mut setup, QURUM);
+}
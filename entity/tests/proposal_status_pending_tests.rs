@@ -0,0 +1,289 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const VOTING_DELAY_MINUTES: usize = 60;
+
+#[test]
+fn it_returns_pending_until_the_voting_delay_has_passed() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_voting_delay_in_minutes_endpoint(VOTING_DELAY_MINUTES);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+
+            let proposal = sc.proposals(proposal_id).get();
+            let voting_delay = VOTING_DELAY_MINUTES as u64 * 60;
+
+            assert_eq!(voting_delay, proposal.starts_at);
+            assert_eq!(voting_delay + VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60, proposal.ends_at);
+
+            // the proposer's weight is recorded right away
+            assert_eq!(managed_biguint!(QURUM), proposal.votes_for);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Pending, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_DELAY_MINUTES as u64 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Active, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .set_block_timestamp((VOTING_DELAY_MINUTES + VOTING_PERIOD_MINUTES_DEFAULT) as u64 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_vote_while_pending() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_voting_delay_in_minutes_endpoint(VOTING_DELAY_MINUTES);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error("voting has not started");
+
+    setup.blockchain.set_block_timestamp(VOTING_DELAY_MINUTES as u64 * 60);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 25), sc.proposals(proposal_id).get().votes_for);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_allows_signing_while_pending_but_does_not_succeed_before_voting_starts() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let signer_address = setup.user_address.clone();
+    let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_voting_delay_in_minutes_endpoint(VOTING_DELAY_MINUTES);
+            sc.create_role(managed_buffer!(b"builder"));
+            sc.assign_role(managed_address!(&owner_address), managed_buffer!(b"builder"));
+            sc.assign_role(managed_address!(&signer_address), managed_buffer!(b"builder"));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&signer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.sign_endpoint(proposal_id, OptionalValue::None);
+
+            assert_eq!(2, sc.proposal_signers(proposal_id, &managed_buffer!(b"builder")).len());
+            assert_eq!(ProposalStatus::Pending, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_DELAY_MINUTES as u64 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_starts_the_execution_delay_of_signatures_collected_while_pending_when_voting_starts() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_voting_delay_in_minutes_endpoint(VOTING_DELAY_MINUTES);
+            sc.change_execution_delay_endpoint(30);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+
+            assert_eq!((VOTING_DELAY_MINUTES + 30) as u64 * 60, sc.proposals(proposal_id).get().executable_after);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_DELAY_MINUTES as u64 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Queued, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp((VOTING_DELAY_MINUTES + 30) as u64 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_allows_the_proposer_to_cancel_while_pending() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_voting_delay_in_minutes_endpoint(VOTING_DELAY_MINUTES);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.cancel_proposal_endpoint(proposal_id);
+
+            assert_eq!(ProposalStatus::Canceled, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
@@ -0,0 +1,286 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_creates_an_optimistic_policy() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let sc_address = setup.contract.address_ref().clone();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy_optimistic_endpoint(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                managed_biguint!(20),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+            );
+
+            let policy = sc.policies(&managed_buffer!(b"testrole")).get(&managed_buffer!(b"testperm")).unwrap();
+
+            assert_eq!(PolicyMethod::Optimistic, policy.method);
+            assert_eq!(managed_biguint!(20), policy.quorum);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_create_an_optimistic_policy_without_objection_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_policy_optimistic_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"), managed_biguint!(0), VOTING_PERIOD_MINUTES_DEFAULT);
+        })
+        .assert_user_error("invalid objection threshold");
+}
+
+#[test]
+fn it_fails_to_create_an_optimistic_policy_if_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_policy_optimistic_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"), managed_biguint!(20), VOTING_PERIOD_MINUTES_DEFAULT);
+        })
+        .assert_user_error("action not allowed by user");
+}
+
+#[test]
+fn it_stays_active_without_objections_until_the_voting_period_ended() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let sc_address = setup.contract.address_ref().clone();
+    let proposer_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                PolicyMethod::Optimistic,
+                managed_biguint!(20),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+            );
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"testrole"));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&sc_address),
+                endpoint: managed_buffer!(b"testendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                actions_permissions,
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Active, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_succeeds_without_quorum_when_objections_stay_below_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let sc_address = setup.contract.address_ref().clone();
+    let proposer_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                PolicyMethod::Optimistic,
+                managed_biguint!(20),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+            );
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"testrole"));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&sc_address),
+                endpoint: managed_buffer!(b"testendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                actions_permissions,
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(19), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_returns_defeated_when_objections_reach_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let sc_address = setup.contract.address_ref().clone();
+    let proposer_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                PolicyMethod::Optimistic,
+                managed_biguint!(20),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+            );
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"testrole"));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&sc_address),
+                endpoint: managed_buffer!(b"testendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                actions_permissions,
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(20), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Defeated, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
@@ -2,6 +2,7 @@ use entity::config::*;
 use entity::governance::proposal::*;
 use entity::governance::*;
 use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
 use setup::*;
@@ -213,3 +214,80 @@ fn it_returns_defeated_when_one_of_one_permission_policies_does_not_meet_quorum(
         })
         .assert_ok();
 }
+
+#[test]
+fn it_returns_defeated_when_for_votes_do_not_meet_the_policy_pass_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let sc_address = setup.contract.address_ref().clone();
+    let proposer_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&sc_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy_weighted_endpoint(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                managed_biguint!(QURUM),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+                OptionalValue::Some(75),
+            );
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"testrole"));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&sc_address),
+                endpoint: managed_buffer!(b"testendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]);
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                actions_permissions,
+            );
+        })
+        .assert_ok();
+
+    // 50 for and 25 against passes the entity's simple majority but not the 75% policy threshold
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Defeated, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
@@ -0,0 +1,330 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::api::ManagedTypeApi;
+use multiversx_sc::codec;
+use multiversx_sc::codec::derive::TopEncode;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::codec::{TopDecode, TopEncode};
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+// the proposal layout before governance parameters were snapshotted.
+#[derive(TopEncode)]
+struct BaselineProposal<M: ManagedTypeApi> {
+    id: u64,
+    proposer: ManagedAddress<M>,
+    content_hash: ManagedBuffer<M>,
+    actions_hash: ManagedBuffer<M>,
+    starts_at: u64,
+    ends_at: u64,
+    was_executed: bool,
+    votes_for: BigUint<M>,
+    votes_against: BigUint<M>,
+    permissions: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+// the policy layout before policies had their own pass threshold and execution delay.
+#[derive(TopEncode)]
+struct BaselinePolicy<M: ManagedTypeApi> {
+    method: PolicyMethod,
+    quorum: BigUint<M>,
+    voting_period_minutes: usize,
+}
+
+#[test]
+fn it_snapshots_the_governance_parameters_on_proposal_creation() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.owner_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
+            sc.create_permission(
+                managed_buffer!(b"perm"),
+                managed_biguint!(0),
+                managed_address!(&proposer_address),
+                ManagedBuffer::new(),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy(managed_buffer!(b"builder"), managed_buffer!(b"perm"), PolicyMethod::Weight, managed_biguint!(20), 10);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let mut permissions = MultiValueManagedVec::new();
+            permissions.push(managed_buffer!(b"perm"));
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                permissions,
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let snapshot = sc.proposals(proposal_id).get().snapshot.unwrap();
+
+            assert_eq!(managed_biguint!(QURUM), snapshot.quorum);
+            assert_eq!(PASS_THRESHOLD_PERCENT_DEFAULT, snapshot.pass_threshold);
+            assert_eq!(QuorumMode::ForOnly, snapshot.quorum_mode);
+            assert!(!snapshot.is_leaderless);
+
+            let role = snapshot.roles.iter().find(|r| r.role == managed_buffer!(b"builder")).unwrap();
+            assert_eq!(1, role.member_amount);
+
+            assert_eq!(1, snapshot.policies.len());
+            let policy = snapshot.policies.get(0);
+            assert_eq!(managed_buffer!(b"builder"), policy.role);
+            assert_eq!(managed_buffer!(b"perm"), policy.permission);
+            assert_eq!(PolicyMethod::Weight, policy.method);
+            assert_eq!(managed_biguint!(20), policy.quorum);
+            assert_eq!(PASS_THRESHOLD_PERCENT_DEFAULT, policy.pass_threshold);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_the_outcome_when_the_quorum_changes_after_creation() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_endpoint(managed_biguint!(QURUM * 2));
+            sc.change_pass_threshold_endpoint(100);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(1), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_the_outcome_when_role_members_change_after_creation() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.owner_address.clone();
+    let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let signer_one = setup.blockchain.create_user_account(&rust_biguint!(1));
+    let new_member_one = setup.blockchain.create_user_account(&rust_biguint!(1));
+    let new_member_two = setup.blockchain.create_user_account(&rust_biguint!(1));
+    let mut proposal_id = 0;
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"builder"));
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"builder"));
+            sc.assign_role(managed_address!(&signer_one), managed_buffer!(b"builder"));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&action_receiver),
+                endpoint: managed_buffer!(b"myendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&signer_one, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.sign_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(b"builder"), managed_address!(&new_member_one));
+            sc.assign_role_endpoint(managed_buffer!(b"builder"), managed_address!(&new_member_two));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(3, sc.get_signer_majority_for_role(&managed_buffer!(b"builder")));
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_evaluates_proposals_stored_without_a_snapshot_against_the_current_configuration() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let proposal = sc.proposals(proposal_id).get();
+            let mut encoded = ManagedBuffer::<DebugApi>::new();
+            proposal.top_encode(&mut encoded).unwrap();
+
+            // cut off the snapshot and the trailing emergency flag to simulate a proposal stored by an earlier version
+            let mut snapshot = ManagedBuffer::<DebugApi>::new();
+            proposal.snapshot.top_encode(&mut snapshot).unwrap();
+            let emergency_flag_len = 1;
+            let legacy = encoded.copy_slice(0, encoded.len() - snapshot.len() - emergency_flag_len).unwrap();
+            let decoded = Proposal::<DebugApi>::top_decode(legacy).unwrap();
+
+            assert!(decoded.snapshot.is_none());
+
+            sc.proposals(proposal_id).set(&decoded);
+            sc.change_quorum_endpoint(managed_biguint!(QURUM * 2));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Defeated, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_decodes_proposals_and_policies_stored_with_the_baseline_layout() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |_| {
+            let baseline_proposal = BaselineProposal::<DebugApi> {
+                id: 7,
+                proposer: managed_address!(&proposer_address),
+                content_hash: managed_buffer!(b"content"),
+                actions_hash: managed_buffer!(b"actions"),
+                starts_at: 10,
+                ends_at: 20,
+                was_executed: true,
+                votes_for: managed_biguint!(30),
+                votes_against: managed_biguint!(40),
+                permissions: ManagedVec::from_single_item(managed_buffer!(b"perm")),
+            };
+            let mut encoded = ManagedBuffer::<DebugApi>::new();
+            baseline_proposal.top_encode(&mut encoded).unwrap();
+
+            let proposal = Proposal::<DebugApi>::top_decode(encoded).unwrap();
+
+            assert_eq!(7, proposal.id);
+            assert_eq!(managed_address!(&proposer_address), proposal.proposer);
+            assert_eq!(managed_buffer!(b"content"), proposal.content_hash);
+            assert_eq!(managed_buffer!(b"actions"), proposal.actions_hash);
+            assert_eq!(10, proposal.starts_at);
+            assert_eq!(20, proposal.ends_at);
+            assert!(proposal.was_executed);
+            assert_eq!(managed_biguint!(30), proposal.votes_for);
+            assert_eq!(managed_biguint!(40), proposal.votes_against);
+            assert_eq!(managed_buffer!(b"perm"), proposal.permissions.get(0).clone_value());
+            assert_eq!(managed_biguint!(0), proposal.votes_abstain);
+            assert_eq!(0, proposal.executable_after);
+            assert!(proposal.snapshot.is_none());
+            assert!(!proposal.is_emergency);
+
+            let baseline_policy = BaselinePolicy::<DebugApi> {
+                method: PolicyMethod::Weight,
+                quorum: managed_biguint!(50),
+                voting_period_minutes: 60,
+            };
+            let mut encoded = ManagedBuffer::<DebugApi>::new();
+            baseline_policy.top_encode(&mut encoded).unwrap();
+
+            let policy = Policy::<DebugApi>::top_decode(encoded).unwrap();
+
+            assert_eq!(PolicyMethod::Weight, policy.method);
+            assert_eq!(managed_biguint!(50), policy.quorum);
+            assert_eq!(60, policy.voting_period_minutes);
+            assert_eq!(0, policy.pass_threshold);
+            assert_eq!(0, policy.execution_delay_minutes);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,385 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::multiversx_chain_vm::tx_mock::TxResult;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const EMERGENCY_VOTING_PERIOD_MINUTES: usize = 60;
+const EMERGENCY_QUORUM: u64 = 100;
+const EMERGENCY_PASS_THRESHOLD: u64 = 66;
+
+fn configure_emergency(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>) {
+    let sc_address = setup.contract.address_ref().clone();
+
+    setup
+        .blockchain
+        .execute_tx(&sc_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_permission(
+                managed_buffer!(b"otherperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"otherendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+
+            sc.set_emergency_config_endpoint(EMERGENCY_VOTING_PERIOD_MINUTES, managed_biguint!(EMERGENCY_QUORUM), EMERGENCY_PASS_THRESHOLD, 0);
+            sc.set_emergency_permissions_endpoint(MultiValueEncoded::from(ManagedVec::from(vec![managed_buffer!(b"testperm")])));
+        })
+        .assert_ok();
+}
+
+fn propose_emergency(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, weight: u64, permission: &[u8]) -> (TxResult, u64) {
+    let sc_address = setup.contract.address_ref().clone();
+    let owner_address = setup.owner_address.clone();
+    let mut proposal_id = 0;
+
+    let result = setup
+        .blockchain
+        .execute_esdt_transfer(&owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(weight), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&sc_address),
+                endpoint: managed_buffer!(b"testendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            });
+
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions));
+            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(permission)]);
+
+            proposal_id = sc.propose_emergency_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                actions_permissions,
+            );
+        });
+
+    (result, proposal_id)
+}
+
+#[test]
+fn it_creates_an_emergency_proposal_with_the_emergency_voting_period_and_thresholds() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    let (result, proposal_id) = propose_emergency(&mut setup, QURUM, b"testperm");
+    result.assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(proposal_id).get();
+            let snapshot = proposal.snapshot.unwrap();
+
+            assert!(proposal.is_emergency);
+            assert_eq!(EMERGENCY_VOTING_PERIOD_MINUTES as u64 * 60, proposal.ends_at);
+            assert_eq!(managed_biguint!(EMERGENCY_QUORUM), snapshot.quorum);
+            assert_eq!(EMERGENCY_PASS_THRESHOLD, snapshot.pass_threshold);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_succeeds_early_once_the_emergency_thresholds_are_reached() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    let (result, proposal_id) = propose_emergency(&mut setup, QURUM, b"testperm");
+    result.assert_ok();
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Active);
+
+    setup.vote_for(&voter_address, proposal_id, EMERGENCY_QUORUM - QURUM);
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Succeeded);
+}
+
+#[test]
+fn it_skips_the_voting_delay_and_starts_the_emergency_execution_delay_once_succeeded() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let sc_address = setup.contract.address_ref().clone();
+    let emergency_execution_delay_minutes = 30;
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    setup
+        .blockchain
+        .execute_tx(&sc_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_endpoint(24 * 60);
+            sc.change_voting_delay_in_minutes_endpoint(10);
+            sc.set_emergency_config_endpoint(
+                EMERGENCY_VOTING_PERIOD_MINUTES,
+                managed_biguint!(EMERGENCY_QUORUM),
+                EMERGENCY_PASS_THRESHOLD,
+                emergency_execution_delay_minutes,
+            );
+        })
+        .assert_ok();
+
+    let (result, proposal_id) = propose_emergency(&mut setup, QURUM, b"testperm");
+    result.assert_ok();
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Active);
+
+    setup.blockchain.set_block_timestamp(60);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(EMERGENCY_QUORUM - QURUM), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+
+            let proposal = sc.proposals(proposal_id).get();
+
+            assert_eq!(0, proposal.starts_at);
+            assert_eq!(60 + emergency_execution_delay_minutes as u64 * 60, proposal.executable_after);
+        })
+        .assert_ok();
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Queued);
+
+    setup.blockchain.set_block_timestamp(60 + emergency_execution_delay_minutes as u64 * 60);
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Succeeded);
+}
+
+#[test]
+fn it_does_not_succeed_early_below_the_emergency_pass_threshold() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let voter_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    let (result, proposal_id) = propose_emergency(&mut setup, 80, b"testperm");
+    result.assert_ok();
+
+    setup.vote_against(&voter_address, proposal_id, 60);
+
+    setup.vote_for(&owner_address, proposal_id, 30);
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Active);
+}
+
+#[test]
+fn it_is_defeated_when_the_emergency_quorum_is_not_reached_within_the_emergency_voting_period() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    let (result, proposal_id) = propose_emergency(&mut setup, 60, b"testperm");
+    result.assert_ok();
+
+    setup.blockchain.set_block_timestamp(EMERGENCY_VOTING_PERIOD_MINUTES as u64 * 60);
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Defeated);
+}
+
+#[test]
+fn it_keeps_the_regular_quorum_if_higher_than_the_emergency_quorum() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_quorum_endpoint(managed_biguint!(200));
+        })
+        .assert_ok();
+
+    let (result, proposal_id) = propose_emergency(&mut setup, EMERGENCY_QUORUM, b"testperm");
+    result.assert_ok();
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Active);
+}
+
+#[test]
+fn it_fails_when_emergency_proposals_are_disabled() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+
+    let (result, _) = propose_emergency(&mut setup, QURUM, b"testperm");
+
+    result.assert_user_error("emergency proposals are disabled");
+}
+
+#[test]
+fn it_fails_when_a_permission_is_not_allowed_in_emergencies() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    let (result, _) = propose_emergency(&mut setup, QURUM, b"otherperm");
+
+    result.assert_user_error("permission not allowed in emergency");
+}
+
+#[test]
+fn it_fails_without_permissions() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            sc.propose_emergency_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_user_error("emergency proposal requires permissions");
+}
+
+#[test]
+fn it_raises_the_emergency_thresholds_to_those_of_weighted_policies() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_policy(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                PolicyMethod::Weight,
+                managed_biguint!(200),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+            );
+            sc.assign_role(managed_address!(&owner_address), managed_buffer!(b"testrole"));
+        })
+        .assert_ok();
+
+    let (result, proposal_id) = propose_emergency(&mut setup, EMERGENCY_QUORUM, b"testperm");
+    result.assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let snapshot = sc.proposals(proposal_id).get().snapshot.unwrap();
+
+            assert_eq!(managed_biguint!(200), snapshot.quorum);
+        })
+        .assert_ok();
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Active);
+
+    setup.vote_for(&voter_address, proposal_id, 100);
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Succeeded);
+}
+
+#[test]
+fn it_keeps_the_signer_requirements_of_policies() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let signer = setup.blockchain.create_user_account(&rust_biguint!(1));
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_policy(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                PolicyMethod::All,
+                managed_biguint!(0),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+            );
+            sc.assign_role(managed_address!(&owner_address), managed_buffer!(b"testrole"));
+            sc.assign_role(managed_address!(&signer), managed_buffer!(b"testrole"));
+        })
+        .assert_ok();
+
+    let (result, proposal_id) = propose_emergency(&mut setup, EMERGENCY_QUORUM, b"testperm");
+    result.assert_ok();
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Active);
+
+    setup
+        .blockchain
+        .execute_tx(&signer, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.sign_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Succeeded);
+}
+
+#[test]
+fn it_applies_the_execution_delay_of_policies_if_longer_than_the_emergency_one() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let policy_execution_delay_minutes = 30;
+    setup.configure_gov_token(true);
+    configure_emergency(&mut setup);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_policy(
+                managed_buffer!(b"testrole"),
+                managed_buffer!(b"testperm"),
+                PolicyMethod::One,
+                managed_biguint!(1),
+                VOTING_PERIOD_MINUTES_DEFAULT,
+            );
+            sc.assign_role(managed_address!(&owner_address), managed_buffer!(b"testrole"));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_policy_execution_delay_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"), policy_execution_delay_minutes);
+        })
+        .assert_ok();
+
+    let (result, proposal_id) = propose_emergency(&mut setup, EMERGENCY_QUORUM, b"testperm");
+    result.assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(proposal_id).get();
+
+            assert_eq!(policy_execution_delay_minutes as u64 * 60, proposal.executable_after);
+        })
+        .assert_ok();
+
+    setup.assert_proposal_status(proposal_id, ProposalStatus::Queued);
+}
@@ -0,0 +1,165 @@
+use entity::config::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_limits_the_active_proposals_per_proposer() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let owner_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_max_active_proposals_endpoint(2);
+        })
+        .assert_ok();
+
+    setup.try_propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"1").assert_ok();
+    setup.try_propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"2").assert_ok();
+    setup.try_propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"3").assert_user_error("too many active proposals");
+
+    // other proposers are not affected
+    setup.try_propose(&owner_address, MIN_PROPOSE_WEIGHT, b"4").assert_ok();
+
+    // ended proposals no longer count as active
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup.try_propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"5").assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal_ids: Vec<u64> = sc.proposer_proposals(&managed_address!(&proposer_address)).iter().collect();
+
+            assert_eq!(vec![1, 2, 4], proposal_ids);
+            assert_eq!(1, sc.proposer_active_proposals(&managed_address!(&proposer_address)).len());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_enforces_a_cooldown_between_proposals() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_proposal_cooldown_in_minutes_endpoint(60);
+        })
+        .assert_ok();
+
+    setup.try_propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"1").assert_ok();
+
+    setup.blockchain.set_block_timestamp(60 * 60 - 1);
+
+    setup.try_propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"2").assert_user_error("proposal cooldown active");
+
+    setup.blockchain.set_block_timestamp(60 * 60);
+
+    setup.try_propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"3").assert_ok();
+}
+
+#[test]
+fn it_exempts_configured_roles_from_rate_limits() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let mut exempt_roles = MultiValueEncoded::new();
+            exempt_roles.push(managed_buffer!(ROLE_BUILTIN_LEADER));
+
+            sc.change_max_active_proposals_endpoint(1);
+            sc.change_proposal_cooldown_in_minutes_endpoint(60);
+            sc.set_proposal_rate_limit_exempt_roles_endpoint(exempt_roles);
+        })
+        .assert_ok();
+
+    setup.try_propose(&owner_address, MIN_PROPOSE_WEIGHT, b"1").assert_ok();
+    setup.try_propose(&owner_address, MIN_PROPOSE_WEIGHT, b"2").assert_ok();
+}
+
+#[test]
+fn it_limits_proposals_created_via_the_plug() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+
+    setup.configure_plug(100, 50);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_max_active_proposals_endpoint(1);
+        })
+        .assert_ok();
+
+    for trusted_host_id in [b"1", b"2"] {
+        setup
+            .blockchain
+            .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+                sc.propose_endpoint(
+                    managed_buffer!(trusted_host_id),
+                    ManagedBuffer::new(),
+                    ManagedBuffer::new(),
+                    ManagedBuffer::new(),
+                    POLL_DEFAULT_ID,
+                    MultiValueManagedVec::new(),
+                );
+            })
+            .assert_ok();
+    }
+
+    // the callback of the second proposal fails, so no proposal is created
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(1, sc.proposer_proposals(&managed_address!(&proposer_address)).len());
+            assert!(sc.proposals(2).is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_change_rate_limits_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_max_active_proposals_endpoint(1);
+        })
+        .assert_user_error("action not allowed by user");
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_proposal_cooldown_in_minutes_endpoint(PROPOSAL_COOLDOWN_MINUTES_MAX);
+        })
+        .assert_user_error("action not allowed by user");
+}
+
+#[test]
+fn it_fails_to_exceed_the_max_proposal_cooldown() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_proposal_cooldown_in_minutes_endpoint(PROPOSAL_COOLDOWN_MINUTES_MAX + 1);
+        })
+        .assert_user_error("max proposal cooldown exceeded");
+}
@@ -1,11 +1,13 @@
 multiversx_sc::imports!();
 
 use entity::config::*;
+use entity::governance::proposal::ProposalStatus;
 use entity::governance::*;
 use entity::permission::PermissionModule;
 use entity::permission::ROLE_BUILTIN_LEADER;
 use entity::plug::*;
 use entity::*;
+use multiversx_sc_scenario::multiversx_chain_vm::tx_mock::TxResult;
 use multiversx_sc_scenario::testing_framework::BlockchainStateWrapper;
 use multiversx_sc_scenario::testing_framework::ContractObjWrapper;
 use multiversx_sc_scenario::*;
@@ -113,6 +115,66 @@ where
             })
             .assert_ok();
     }
+
+    pub fn propose(&mut self, proposer: &Address, weight: u64, trusted_host_id: &[u8]) -> u64 {
+        self.propose_with_nonce(proposer, 0, weight, trusted_host_id)
+    }
+
+    pub fn propose_with_nonce(&mut self, proposer: &Address, nonce: u64, weight: u64, trusted_host_id: &[u8]) -> u64 {
+        let (result, proposal_id) = self.execute_propose(proposer, nonce, weight, trusted_host_id);
+        result.assert_ok();
+
+        proposal_id
+    }
+
+    pub fn try_propose(&mut self, proposer: &Address, weight: u64, trusted_host_id: &[u8]) -> TxResult {
+        let (result, _) = self.execute_propose(proposer, 0, weight, trusted_host_id);
+
+        result
+    }
+
+    pub fn vote_for(&mut self, voter: &Address, proposal_id: u64, weight: u64) {
+        self.blockchain
+            .execute_esdt_transfer(voter, &self.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(weight), |sc| {
+                sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+            })
+            .assert_ok();
+    }
+
+    pub fn vote_against(&mut self, voter: &Address, proposal_id: u64, weight: u64) {
+        self.blockchain
+            .execute_esdt_transfer(voter, &self.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(weight), |sc| {
+                sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+            })
+            .assert_ok();
+    }
+
+    pub fn assert_proposal_status(&mut self, proposal_id: u64, expected: ProposalStatus) {
+        self.blockchain
+            .execute_query(&self.contract, |sc| {
+                assert_eq!(expected, sc.get_proposal_status_view(proposal_id));
+            })
+            .assert_ok();
+    }
+
+    fn execute_propose(&mut self, proposer: &Address, nonce: u64, weight: u64, trusted_host_id: &[u8]) -> (TxResult, u64) {
+        let mut proposal_id = 0;
+
+        let result = self
+            .blockchain
+            .execute_esdt_transfer(proposer, &self.contract, ENTITY_GOV_TOKEN_ID, nonce, &rust_biguint!(weight), |sc| {
+                proposal_id = sc.propose_endpoint(
+                    managed_buffer!(trusted_host_id),
+                    ManagedBuffer::new(),
+                    ManagedBuffer::new(),
+                    ManagedBuffer::new(),
+                    POLL_DEFAULT_ID,
+                    MultiValueManagedVec::new(),
+                );
+            });
+
+        (result, proposal_id)
+    }
 }
 
 #[test]
@@ -0,0 +1,371 @@
+use ed25519_dalek::{Signer, SigningKey};
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::signed_vote::*;
+use entity::governance::*;
+use entity::Entity;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::multiversx_chain_vm::crypto_functions::keccak256;
+use multiversx_sc_scenario::multiversx_chain_vm::tx_mock::TxResult;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const SIGNED_VOTE_EXPIRES_AT: u64 = 3600;
+const SIGNED_VOTE_CHAIN_ID: &[u8] = b"D";
+
+struct TestSignedVote {
+    proposal_id: u64,
+    vote_type: VoteType,
+    option_id: u8,
+    min_weight: u64,
+    nonce: u64,
+    expires_at: u64,
+}
+
+fn enable_signed_votes(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>) {
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.signed_vote_chain_id().set(managed_buffer!(SIGNED_VOTE_CHAIN_ID));
+        })
+        .assert_ok();
+}
+
+fn create_voter(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, seed: u8) -> (SigningKey, Address) {
+    let signing_key = SigningKey::from_bytes(&[seed; 32]);
+    let voter_address = Address::from(signing_key.verifying_key().to_bytes());
+
+    setup.blockchain.create_user_account_fixed_address(&voter_address, &rust_biguint!(0));
+    setup
+        .blockchain
+        .set_esdt_balance(&voter_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+
+    (signing_key, voter_address)
+}
+
+fn lock_escrow(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, voter: &Address, amount: u64) {
+    setup
+        .blockchain
+        .execute_esdt_transfer(voter, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(amount), |sc| {
+            sc.lock_escrow_endpoint(ESCROW_LOCK_MINUTES_MAX);
+        })
+        .assert_ok();
+}
+
+fn sign_vote(setup: &EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, signing_key: &SigningKey, vote: &TestSignedVote) -> [u8; 64] {
+    let mut signable = Vec::new();
+
+    signable.extend_from_slice(&signing_key.verifying_key().to_bytes());
+    signable.extend_from_slice(setup.contract.address_ref().as_bytes());
+    signable.extend_from_slice(SIGNED_VOTE_CHAIN_ID);
+    signable.extend_from_slice(&vote.proposal_id.to_be_bytes());
+    signable.extend_from_slice(&[vote.vote_type.clone() as u8, vote.option_id]);
+    signable.extend_from_slice(&vote.nonce.to_be_bytes());
+    signable.extend_from_slice(&vote.expires_at.to_be_bytes());
+    signable.extend_from_slice(&rust_biguint!(vote.min_weight).to_bytes_be());
+
+    signing_key.sign(&keccak256(&signable)).to_bytes()
+}
+
+fn submit_signed_votes(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, votes: Vec<(Address, TestSignedVote, [u8; 64])>) -> TxResult {
+    let relayer_address = setup.user_address.clone();
+
+    setup.blockchain.execute_tx(&relayer_address, &setup.contract, &rust_biguint!(0), |sc| {
+        let mut signed_votes = MultiValueEncoded::new();
+
+        for (voter, vote, signature) in votes.iter() {
+            signed_votes.push(SignedVote::<DebugApi> {
+                voter: managed_address!(voter),
+                proposal_id: vote.proposal_id,
+                vote_type: vote.vote_type.clone(),
+                option_id: vote.option_id,
+                min_weight: managed_biguint!(vote.min_weight),
+                nonce: vote.nonce,
+                expires_at: vote.expires_at,
+                signature: ManagedByteArray::new_from_bytes(signature),
+            });
+        }
+
+        sc.submit_signed_votes_endpoint(signed_votes);
+    })
+}
+
+fn for_vote(proposal_id: u64, min_weight: u64, nonce: u64) -> TestSignedVote {
+    TestSignedVote {
+        proposal_id,
+        vote_type: VoteType::For,
+        option_id: 0,
+        min_weight,
+        nonce,
+        expires_at: SIGNED_VOTE_EXPIRES_AT,
+    }
+}
+
+#[test]
+fn it_casts_a_signed_vote_with_the_voters_escrowed_weight() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+    enable_signed_votes(&mut setup);
+
+    let (signing_key, voter_address) = create_voter(&mut setup, 1);
+    lock_escrow(&mut setup, &voter_address, 100);
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    let vote = for_vote(proposal_id, 100, 1);
+    let signature = sign_vote(&setup, &signing_key, &vote);
+
+    submit_signed_votes(&mut setup, vec![(voter_address.clone(), vote, signature)]).assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(proposal_id).get();
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT) + managed_biguint!(100), proposal.votes_for);
+            assert_eq!(1, sc.signed_vote_nonces(&managed_address!(&voter_address)).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_casts_a_batch_of_signed_votes_with_escrowed_and_delegated_weight() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let delegate_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    enable_signed_votes(&mut setup);
+
+    let (escrow_key, escrow_voter) = create_voter(&mut setup, 1);
+    let (delegation_key, delegation_voter) = create_voter(&mut setup, 2);
+
+    lock_escrow(&mut setup, &escrow_voter, 100);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegation_voter, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(30), |sc| {
+            sc.delegate_endpoint(managed_address!(&delegate_address));
+        })
+        .assert_ok();
+
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    let escrow_vote = for_vote(proposal_id, 100, 1);
+    let escrow_signature = sign_vote(&setup, &escrow_key, &escrow_vote);
+
+    let delegation_vote = TestSignedVote {
+        vote_type: VoteType::Against,
+        ..for_vote(proposal_id, 30, 1)
+    };
+    let delegation_signature = sign_vote(&setup, &delegation_key, &delegation_vote);
+
+    submit_signed_votes(
+        &mut setup,
+        vec![
+            (escrow_voter, escrow_vote, escrow_signature),
+            (delegation_voter, delegation_vote, delegation_signature),
+        ],
+    )
+    .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(proposal_id).get();
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT) + managed_biguint!(100), proposal.votes_for);
+            assert_eq!(managed_biguint!(30), proposal.votes_against);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_replay_a_signed_vote() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+    enable_signed_votes(&mut setup);
+
+    let (signing_key, voter_address) = create_voter(&mut setup, 1);
+    lock_escrow(&mut setup, &voter_address, 100);
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    let signature = sign_vote(&setup, &signing_key, &for_vote(proposal_id, 100, 1));
+
+    submit_signed_votes(&mut setup, vec![(voter_address.clone(), for_vote(proposal_id, 100, 1), signature)]).assert_ok();
+
+    submit_signed_votes(&mut setup, vec![(voter_address, for_vote(proposal_id, 100, 1), signature)]).assert_user_error("invalid signed vote nonce");
+}
+
+#[test]
+fn it_fails_to_submit_an_expired_signed_vote() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+    enable_signed_votes(&mut setup);
+
+    let (signing_key, voter_address) = create_voter(&mut setup, 1);
+    lock_escrow(&mut setup, &voter_address, 100);
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    let vote = for_vote(proposal_id, 100, 1);
+    let signature = sign_vote(&setup, &signing_key, &vote);
+
+    setup.blockchain.set_block_timestamp(SIGNED_VOTE_EXPIRES_AT + 1);
+
+    submit_signed_votes(&mut setup, vec![(voter_address, vote, signature)]).assert_user_error("signed vote expired");
+}
+
+#[test]
+fn it_fails_to_submit_a_signed_vote_signed_by_someone_else() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+    enable_signed_votes(&mut setup);
+
+    let (_, voter_address) = create_voter(&mut setup, 1);
+    let (other_key, _) = create_voter(&mut setup, 2);
+    lock_escrow(&mut setup, &voter_address, 100);
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    let vote = for_vote(proposal_id, 100, 1);
+    let signature = sign_vote(&setup, &other_key, &vote);
+
+    submit_signed_votes(&mut setup, vec![(voter_address, vote, signature)]).assert_error(10, "invalid signature");
+}
+
+#[test]
+fn it_fails_to_submit_a_signed_vote_with_a_changed_vote_type() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+    enable_signed_votes(&mut setup);
+
+    let (signing_key, voter_address) = create_voter(&mut setup, 1);
+    lock_escrow(&mut setup, &voter_address, 100);
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    let signature = sign_vote(&setup, &signing_key, &for_vote(proposal_id, 100, 1));
+    let tampered_vote = TestSignedVote {
+        vote_type: VoteType::Against,
+        ..for_vote(proposal_id, 100, 1)
+    };
+
+    submit_signed_votes(&mut setup, vec![(voter_address, tampered_vote, signature)]).assert_error(10, "invalid signature");
+}
+
+#[test]
+fn it_fails_to_submit_a_signed_vote_when_the_available_weight_is_below_the_signed_min_weight() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+    enable_signed_votes(&mut setup);
+
+    let (signing_key, voter_address) = create_voter(&mut setup, 1);
+    lock_escrow(&mut setup, &voter_address, 100);
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    let vote = for_vote(proposal_id, 101, 1);
+    let signature = sign_vote(&setup, &signing_key, &vote);
+
+    submit_signed_votes(&mut setup, vec![(voter_address, vote, signature)]).assert_user_error("insufficient vote weight");
+}
+
+#[test]
+fn it_casts_all_available_weight_when_it_exceeds_the_signed_min_weight() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+    enable_signed_votes(&mut setup);
+
+    let (signing_key, voter_address) = create_voter(&mut setup, 1);
+    lock_escrow(&mut setup, &voter_address, 100);
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    let vote = for_vote(proposal_id, 50, 1);
+    let signature = sign_vote(&setup, &signing_key, &vote);
+
+    submit_signed_votes(&mut setup, vec![(voter_address, vote, signature)]).assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT) + managed_biguint!(100), sc.proposals(proposal_id).get().votes_for);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_submit_a_signed_vote_when_signed_votes_are_not_enabled() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+
+    let (signing_key, voter_address) = create_voter(&mut setup, 1);
+    lock_escrow(&mut setup, &voter_address, 100);
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    let vote = for_vote(proposal_id, 100, 1);
+    let signature = sign_vote(&setup, &signing_key, &vote);
+
+    submit_signed_votes(&mut setup, vec![(voter_address, vote, signature)]).assert_user_error("signed votes are not enabled");
+}
+
+#[test]
+fn it_fails_to_submit_a_signed_vote_signed_for_another_chain() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+    enable_signed_votes(&mut setup);
+
+    let (signing_key, voter_address) = create_voter(&mut setup, 1);
+    lock_escrow(&mut setup, &voter_address, 100);
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    let vote = for_vote(proposal_id, 100, 1);
+    let signature = sign_vote(&setup, &signing_key, &vote);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.signed_vote_chain_id().set(managed_buffer!(b"T"));
+        })
+        .assert_ok();
+
+    submit_signed_votes(&mut setup, vec![(voter_address, vote, signature)]).assert_error(10, "invalid signature");
+}
+
+#[test]
+fn it_sets_the_signed_vote_chain_id() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_trusted_host();
+
+    setup
+        .blockchain
+        .execute_tx(&setup.trusted_host_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_signed_vote_chain_id_endpoint(managed_buffer!(SIGNED_VOTE_CHAIN_ID));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_buffer!(SIGNED_VOTE_CHAIN_ID), sc.signed_vote_chain_id().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_set_the_signed_vote_chain_id_when_not_trusted_host() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    setup.configure_trusted_host();
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_signed_vote_chain_id_endpoint(managed_buffer!(SIGNED_VOTE_CHAIN_ID));
+        })
+        .assert_user_error("action not allowed by user");
+}
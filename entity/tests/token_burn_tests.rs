@@ -1,3 +1,4 @@
+use entity::config::*;
 use entity::governance::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
@@ -27,6 +28,30 @@ fn it_burns_when_contract_calls_itself() {
     // TODO: add balance check
 }
 
+#[test]
+fn it_tracks_the_gov_token_supply_when_burning_gov_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .set_esdt_local_roles(setup.contract.address_ref(), ENTITY_GOV_TOKEN_ID, &[EsdtLocalRole::Burn]);
+
+    setup
+        .blockchain
+        .set_esdt_balance(setup.contract.address_ref(), ENTITY_GOV_TOKEN_ID, &rust_biguint!(1));
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.burn_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 0, managed_biguint!(1));
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT - 1), sc.gov_token_supply().get());
+        })
+        .assert_ok();
+}
+
 #[test]
 fn it_fails_when_caller_not_self() {
     let mut setup = EntitySetup::new(entity::contract_obj);
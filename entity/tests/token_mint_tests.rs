@@ -1,3 +1,4 @@
+use entity::config::*;
 use entity::governance::*;
 use multiversx_sc::types::*;
 use multiversx_sc_scenario::*;
@@ -23,6 +24,26 @@ fn it_mints_when_contract_calls_itself() {
     // TODO: add balance check
 }
 
+#[test]
+fn it_tracks_the_gov_token_supply_when_minting_gov_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .set_esdt_local_roles(setup.contract.address_ref(), ENTITY_GOV_TOKEN_ID, &[EsdtLocalRole::Mint]);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.mint_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 0, managed_biguint!(1_000));
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 1_000), sc.gov_token_supply().get());
+        })
+        .assert_ok();
+}
+
 #[test]
 fn it_fails_when_caller_not_self() {
     let mut setup = EntitySetup::new(entity::contract_obj);
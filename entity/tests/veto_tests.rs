@@ -0,0 +1,293 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+fn assign_guardian(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, guardian: &Address) {
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role_endpoint(managed_buffer!(ROLE_BUILTIN_GUARDIAN), managed_address!(guardian));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_vetoes_an_active_proposal_and_lets_voters_withdraw() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let guardian_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    assign_guardian(&mut setup, &guardian_address);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.veto_endpoint(proposal_id);
+
+            assert_eq!(ProposalStatus::Vetoed, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(1), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error("proposal is not active");
+
+    // voting period has not ended, but tokens are withdrawable right away
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_endpoint();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&proposer_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+}
+
+#[test]
+fn it_vetoes_a_queued_proposal_so_it_can_not_be_executed() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let guardian_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let action_receiver = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    assign_guardian(&mut setup, &guardian_address);
+
+    setup.blockchain.set_egld_balance(setup.contract.address_ref(), &rust_biguint!(1000));
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(ROLE_BUILTIN_LEADER));
+            sc.create_permission(
+                managed_buffer!(b"perm"),
+                managed_biguint!(5),
+                managed_address!(&action_receiver),
+                managed_buffer!(b"myendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy(
+                managed_buffer!(ROLE_BUILTIN_LEADER),
+                managed_buffer!(b"perm"),
+                PolicyMethod::Quorum,
+                BigUint::from(1u64),
+                10,
+            );
+            sc.execution_delay_in_minutes().set(60);
+        })
+        .assert_ok();
+
+    let actions = || {
+        vec![Action::<DebugApi> {
+            destination: managed_address!(&action_receiver),
+            endpoint: managed_buffer!(b"myendpoint"),
+            arguments: ManagedVec::new(),
+            gas_limit: 5_000_000u64,
+            value: managed_biguint!(5),
+            payments: ManagedVec::new(),
+        }]
+    };
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let actions_hash = sc.calculate_actions_hash(&ManagedVec::from(actions()));
+            let actions_permissions = MultiValueManagedVec::from(vec![managed_buffer!(b"perm")]);
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                managed_buffer!(b"a"),
+                managed_buffer!(b"b"),
+                actions_hash,
+                POLL_DEFAULT_ID,
+                actions_permissions,
+            );
+
+            assert_eq!(ProposalStatus::Queued, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.veto_endpoint(proposal_id);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(60 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&proposer_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.execute_endpoint(proposal_id, MultiValueManagedVec::from(actions()));
+        })
+        .assert_user_error("proposal has been vetoed");
+}
+
+#[test]
+fn it_fails_to_veto_a_proposal_that_has_ended() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let guardian_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    assign_guardian(&mut setup, &guardian_address);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            assert_eq!(ProposalStatus::Defeated, sc.get_proposal_status_view(proposal_id));
+
+            sc.veto_endpoint(proposal_id);
+        })
+        .assert_user_error("proposal can not be vetoed");
+}
+
+#[test]
+fn it_fails_when_caller_not_guardian() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let guardian_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    assign_guardian(&mut setup, &guardian_address);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.veto_endpoint(proposal_id);
+        })
+        .assert_user_error("caller must be guardian");
+
+    // the dao can remove the guardian role through a vote
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.remove_guardian_role_endpoint();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.veto_endpoint(proposal_id);
+        })
+        .assert_user_error("caller must be guardian");
+}
+
+#[test]
+fn it_uses_the_built_in_guardian_role_by_default() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(managed_buffer!(ROLE_BUILTIN_GUARDIAN), sc.guardian_role().get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_veto_a_succeeded_proposal_after_its_queue_window() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let guardian_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    assign_guardian(&mut setup, &guardian_address);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_endpoint(60);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 60 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&guardian_address, &setup.contract, &rust_biguint!(0), |sc| {
+            assert_eq!(ProposalStatus::Succeeded, sc.get_proposal_status_view(proposal_id));
+
+            sc.veto_endpoint(proposal_id);
+        })
+        .assert_user_error("proposal can not be vetoed");
+}
@@ -0,0 +1,173 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+#[test]
+fn it_records_a_vote_receipt() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+    let poll_option_id = 2u8;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::Some(poll_option_id));
+
+            let receipts = sc.vote_receipts(proposal_id, &managed_address!(&voter_address));
+            assert_eq!(1, receipts.len());
+
+            let receipt = receipts.get(1);
+            assert_eq!(VoteType::Against, receipt.vote_type);
+            assert_eq!(managed_biguint!(25), receipt.weight);
+            assert_eq!(poll_option_id, receipt.poll_option);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_changes_a_vote() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(10), |sc| {
+            sc.vote_abstain_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_endpoint(proposal_id, VoteType::Against);
+
+            let proposal = sc.proposals(proposal_id).get();
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT), proposal.votes_for);
+            assert_eq!(managed_biguint!(35), proposal.votes_against);
+            assert_eq!(managed_biguint!(0), proposal.votes_abstain);
+
+            for receipt in sc.vote_receipts(proposal_id, &managed_address!(&voter_address)).iter() {
+                assert_eq!(VoteType::Against, receipt.vote_type);
+            }
+
+            // tokens remain locked
+            assert_eq!(2, sc.withdrawable_votes(proposal_id, &managed_address!(&voter_address)).len());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_change_a_vote_when_not_voted() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_endpoint(proposal_id, VoteType::Against);
+        })
+        .assert_user_error("no vote to change");
+}
+
+#[test]
+fn it_fails_to_change_a_vote_when_voting_period_has_ended() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(25), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_endpoint(proposal_id, VoteType::Against);
+        })
+        .assert_user_error("proposal is not active");
+}
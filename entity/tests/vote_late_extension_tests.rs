@@ -0,0 +1,431 @@
+use entity::config::*;
+use entity::governance::proposal::*;
+use entity::governance::*;
+use entity::permission::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const VOTING_PERIOD_SECONDS: u64 = VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60;
+
+fn configure_late_vote_extension(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, max_extensions: usize) {
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_late_vote_extension_endpoint(60, 30, max_extensions);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_extends_the_voting_period_when_a_late_vote_flips_the_outcome() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_late_vote_extension(&mut setup, 2);
+
+    let proposal_id = setup.propose(&proposer_address, QURUM, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 10 * 60);
+
+    setup.vote_against(&owner_address, proposal_id, QURUM + 1);
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(proposal_id).get();
+
+            assert_eq!(VOTING_PERIOD_SECONDS + 30 * 60, proposal.ends_at);
+            assert_eq!(1, sc.proposal_extensions(proposal_id).get());
+            assert_eq!(ProposalStatus::Active, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS + 30 * 60);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(ProposalStatus::Defeated, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_does_not_extend_when_the_outcome_does_not_flip() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_late_vote_extension(&mut setup, 2);
+
+    let proposal_id = setup.propose(&proposer_address, QURUM, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 10 * 60);
+
+    setup.vote_against(&owner_address, proposal_id, 10);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(VOTING_PERIOD_SECONDS, sc.proposals(proposal_id).get().ends_at);
+            assert_eq!(0, sc.proposal_extensions(proposal_id).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_does_not_extend_when_the_vote_is_cast_before_the_late_vote_window() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_late_vote_extension(&mut setup, 2);
+
+    let proposal_id = setup.propose(&proposer_address, QURUM, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 61 * 60);
+
+    setup.vote_against(&owner_address, proposal_id, QURUM + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(VOTING_PERIOD_SECONDS, sc.proposals(proposal_id).get().ends_at);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_does_not_extend_when_disabled() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+
+    let proposal_id = setup.propose(&proposer_address, QURUM, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 10 * 60);
+
+    setup.vote_against(&owner_address, proposal_id, QURUM + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(VOTING_PERIOD_SECONDS, sc.proposals(proposal_id).get().ends_at);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_stops_extending_after_the_max_extensions() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_late_vote_extension(&mut setup, 1);
+
+    let proposal_id = setup.propose(&proposer_address, QURUM, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 10 * 60);
+
+    setup.vote_against(&owner_address, proposal_id, QURUM + 1);
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS + 20 * 60);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.user_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(VOTING_PERIOD_SECONDS + 30 * 60, sc.proposals(proposal_id).get().ends_at);
+            assert_eq!(1, sc.proposal_extensions(proposal_id).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_extends_when_a_late_revoked_vote_flips_the_outcome() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(false);
+    configure_late_vote_extension(&mut setup, 2);
+
+    let proposal_id = setup.propose(&proposer_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(QURUM), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 10 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.revoke_vote_endpoint(proposal_id);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(VOTING_PERIOD_SECONDS + 30 * 60, sc.proposals(proposal_id).get().ends_at);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_shifts_the_execution_delay_along_with_the_extension() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_late_vote_extension(&mut setup, 2);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_execution_delay_endpoint(120);
+        })
+        .assert_ok();
+
+    let proposal_id = setup.propose(&proposer_address, QURUM, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 10 * 60);
+
+    setup.vote_against(&owner_address, proposal_id, QURUM + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            let proposal = sc.proposals(proposal_id).get();
+
+            assert_eq!(VOTING_PERIOD_SECONDS + 30 * 60, proposal.ends_at);
+            assert_eq!(proposal.ends_at + 120 * 60, proposal.executable_after);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_keeps_used_delegations_and_escrows_locked_until_the_extended_end() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let owner_address = setup.owner_address.clone();
+    let delegator_address = setup.blockchain.create_user_account(&rust_biguint!(0));
+
+    setup.configure_gov_token(true);
+    configure_late_vote_extension(&mut setup, 2);
+
+    setup.blockchain.set_esdt_balance(&owner_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(1_000_000_000_000));
+    setup.blockchain.set_esdt_balance(&delegator_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(100));
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100_000_000_000), |sc| {
+            sc.lock_escrow_endpoint(VOTING_PERIOD_MINUTES_DEFAULT);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&delegator_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(100), |sc| {
+            sc.delegate_endpoint(managed_address!(&owner_address));
+        })
+        .assert_ok();
+
+    let proposal_id = setup.propose(&proposer_address, QURUM, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 10 * 60);
+
+    setup.vote_against(&owner_address, proposal_id, QURUM + 1);
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.undelegate_endpoint();
+        })
+        .assert_user_error("delegation is locked by an active proposal");
+
+    setup
+        .blockchain
+        .execute_tx(&owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_escrow_endpoint();
+        })
+        .assert_user_error("escrow is locked by an active proposal");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS + 30 * 60);
+
+    setup
+        .blockchain
+        .execute_tx(&delegator_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.undelegate_endpoint();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_tx(&owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_escrow_endpoint();
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_extends_when_a_late_replaced_ballot_flips_the_outcome() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    setup.configure_gov_token(true);
+    configure_late_vote_extension(&mut setup, 2);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_mode_endpoint(VoteMode::Replace);
+        })
+        .assert_ok();
+
+    let proposal_id = setup.propose(&proposer_address, QURUM, b"id");
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 10 * 60);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(1), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(VOTING_PERIOD_SECONDS + 30 * 60, sc.proposals(proposal_id).get().ends_at);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_does_not_extend_when_a_late_replaced_ballot_keeps_the_outcome() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let proposer_address = setup.user_address.clone();
+    let owner_address = setup.owner_address.clone();
+    setup.configure_gov_token(true);
+    configure_late_vote_extension(&mut setup, 2);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.change_vote_mode_endpoint(VoteMode::Replace);
+            sc.change_quorum_mode_endpoint(QuorumMode::ForAndAgainst);
+        })
+        .assert_ok();
+
+    let proposal_id = setup.propose(&proposer_address, 40, b"id");
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(20), |sc| {
+            sc.vote_abstain_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 10 * 60);
+
+    // the replaced abstain weight alone would make the proposal pass, but the added weight defeats it again.
+    setup
+        .blockchain
+        .execute_esdt_transfer(&owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(30), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(VOTING_PERIOD_SECONDS, sc.proposals(proposal_id).get().ends_at);
+            assert_eq!(0, sc.proposal_extensions(proposal_id).get());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_extends_when_late_objections_defeat_an_optimistic_proposal() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let proposer_address = setup.user_address.clone();
+    let sc_address = setup.contract.address_ref().clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    configure_late_vote_extension(&mut setup, 2);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.create_role(managed_buffer!(b"testrole"));
+            sc.create_permission(
+                managed_buffer!(b"testperm"),
+                managed_biguint!(0),
+                managed_address!(&sc_address),
+                managed_buffer!(b"testendpoint"),
+                ManagedVec::new(),
+                ManagedVec::new(),
+            );
+            sc.create_policy_optimistic_endpoint(managed_buffer!(b"testrole"), managed_buffer!(b"testperm"), managed_biguint!(20), VOTING_PERIOD_MINUTES_DEFAULT);
+            sc.assign_role(managed_address!(&proposer_address), managed_buffer!(b"testrole"));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&proposer_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            let mut actions = Vec::<Action<DebugApi>>::new();
+            actions.push(Action::<DebugApi> {
+                destination: managed_address!(&sc_address),
+                endpoint: managed_buffer!(b"testendpoint"),
+                arguments: ManagedVec::new(),
+                gas_limit: 5_000_000u64,
+                value: managed_biguint!(0),
+                payments: ManagedVec::new(),
+            });
+
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                sc.calculate_actions_hash(&ManagedVec::from(actions)),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::from(vec![managed_buffer!(b"testperm")]),
+            );
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS - 10 * 60);
+
+    setup.vote_against(&owner_address, proposal_id, 20);
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_SECONDS + 1);
+
+    setup
+        .blockchain
+        .execute_query(&setup.contract, |sc| {
+            assert_eq!(VOTING_PERIOD_SECONDS + 30 * 60, sc.proposals(proposal_id).get().ends_at);
+            assert_eq!(ProposalStatus::Active, sc.get_proposal_status_view(proposal_id));
+        })
+        .assert_ok();
+}
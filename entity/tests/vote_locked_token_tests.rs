@@ -0,0 +1,182 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const LOCKED_TOKEN_ID: &[u8] = b"LKTOKEN-abcdef";
+const LOCKED_TOKEN_NONCE: u64 = 1;
+const MAX_LOCK_EPOCHS: u64 = 100;
+const CURRENT_EPOCH: u64 = 10;
+
+fn configure_locked_token(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, unlock_epoch: u64) {
+    let voter_address = setup.user_address.clone();
+
+    // attributes: 4 bytes of unrelated data followed by the unlock epoch
+    let mut attributes = vec![0xff; 4];
+    attributes.extend_from_slice(&unlock_epoch.to_be_bytes());
+
+    setup
+        .blockchain
+        .set_nft_balance(&voter_address, LOCKED_TOKEN_ID, LOCKED_TOKEN_NONCE, &rust_biguint!(1_000), &attributes);
+
+    setup.blockchain.set_block_epoch(CURRENT_EPOCH);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_vote_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 100, 18, true);
+            sc.set_vote_token_endpoint(managed_token_id!(LOCKED_TOKEN_ID), 100, 18, true);
+            sc.set_locked_vote_token_layout_endpoint(managed_token_id!(LOCKED_TOKEN_ID), 4, 8, MAX_LOCK_EPOCHS);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_votes_with_the_full_amount_of_a_fully_locked_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_locked_token(&mut setup, CURRENT_EPOCH + MAX_LOCK_EPOCHS + 50);
+
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, LOCKED_TOKEN_ID, LOCKED_TOKEN_NONCE, &rust_biguint!(1_000), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 1_000), sc.proposals(proposal_id).get().votes_for);
+            assert_eq!(
+                managed_biguint!(1_000),
+                sc.guarded_vote_tokens(&managed_token_id!(LOCKED_TOKEN_ID), LOCKED_TOKEN_NONCE).get()
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_votes_with_a_share_of_a_partially_locked_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_locked_token(&mut setup, CURRENT_EPOCH + MAX_LOCK_EPOCHS / 4);
+
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, LOCKED_TOKEN_ID, LOCKED_TOKEN_NONCE, &rust_biguint!(1_000), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+
+            assert_eq!(managed_biguint!(250), sc.proposals(proposal_id).get().votes_against);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_vote_with_an_expired_locked_token() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_locked_token(&mut setup, CURRENT_EPOCH - 1);
+
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, LOCKED_TOKEN_ID, LOCKED_TOKEN_NONCE, &rust_biguint!(1_000), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error("vote weight must be greater than 0");
+}
+
+#[test]
+fn it_withdraws_locked_tokens_after_voting() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(true);
+    configure_locked_token(&mut setup, CURRENT_EPOCH + MAX_LOCK_EPOCHS / 2);
+
+    let proposal_id = setup.propose(&owner_address, MIN_PROPOSE_WEIGHT, b"id");
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, LOCKED_TOKEN_ID, LOCKED_TOKEN_NONCE, &rust_biguint!(1_000), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_endpoint();
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_nft_balance::<Vec<u8>>(&voter_address, LOCKED_TOKEN_ID, LOCKED_TOKEN_NONCE, &rust_biguint!(1_000), None);
+}
+
+#[test]
+fn it_fails_to_set_a_layout_for_unregistered_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_locked_vote_token_layout_endpoint(managed_token_id!(LOCKED_TOKEN_ID), 0, 8, MAX_LOCK_EPOCHS);
+        })
+        .assert_user_error("token is not a vote token");
+}
+
+#[test]
+fn it_fails_to_set_an_invalid_layout() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_locked_vote_token_layout_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 0, 9, MAX_LOCK_EPOCHS);
+        })
+        .assert_user_error("invalid unlock epoch length");
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_locked_vote_token_layout_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 0, 8, 0);
+        })
+        .assert_user_error("invalid max lock epochs");
+}
+
+#[test]
+fn it_fails_when_caller_not_self() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+
+    setup.configure_gov_token(true);
+
+    setup
+        .blockchain
+        .execute_tx(&setup.owner_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_locked_vote_token_layout_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 0, 8, MAX_LOCK_EPOCHS);
+        })
+        .assert_user_error("action not allowed by user");
+}
@@ -0,0 +1,226 @@
+use entity::config::*;
+use entity::governance::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::whitebox_legacy::TxTokenTransfer;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const LP_TOKEN_ID: &[u8] = b"LPT-abcdef";
+const LOCKED_TOKEN_ID: &[u8] = b"LOCKED-abcdef";
+
+fn configure_vote_tokens(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>) {
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_vote_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 100, 2, true);
+            sc.set_vote_token_endpoint(managed_token_id!(LP_TOKEN_ID), 200, 4, true);
+            sc.set_vote_token_endpoint(managed_token_id!(LOCKED_TOKEN_ID), 50, 2, false);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_weighs_mixed_vote_token_baskets() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+    configure_vote_tokens(&mut setup);
+
+    setup.blockchain.set_esdt_balance(&voter_address, LP_TOKEN_ID, &rust_biguint!(10_000));
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    let transfers = vec![
+        TxTokenTransfer {
+            token_identifier: ENTITY_GOV_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(25),
+        },
+        TxTokenTransfer {
+            token_identifier: LP_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(10_000),
+        },
+    ];
+
+    setup
+        .blockchain
+        .execute_esdt_multi_transfer(&voter_address, &setup.contract, &transfers, |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+
+            // 25 gov tokens + 1.0000 LP normalized to 1.00 gov tokens at 2x
+            assert_eq!(managed_biguint!(225), sc.proposals(proposal_id).get().votes_against);
+            assert_eq!(managed_biguint!(10_000), sc.guarded_vote_tokens(&managed_token_id!(LP_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup.blockchain.set_block_timestamp(VOTING_PERIOD_MINUTES_DEFAULT as u64 * 60 + 1);
+
+    setup
+        .blockchain
+        .execute_tx(&voter_address, &setup.contract, &rust_biguint!(0), |sc| {
+            sc.withdraw_endpoint();
+
+            assert_eq!(managed_biguint!(0), sc.guarded_vote_tokens(&managed_token_id!(LP_TOKEN_ID), 0).get());
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .check_esdt_balance(&voter_address, ENTITY_GOV_TOKEN_ID, &rust_biguint!(ENTITY_GOV_TOKEN_SUPPLY));
+
+    setup.blockchain.check_esdt_balance(&voter_address, LP_TOKEN_ID, &rust_biguint!(10_000));
+}
+
+#[test]
+fn it_tracks_returned_nft_votes_per_collection() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(false);
+    configure_vote_tokens(&mut setup);
+
+    setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(100), &0u32);
+    setup.blockchain.set_nft_balance(&voter_address, LOCKED_TOKEN_ID, 1, &rust_biguint!(100), &0u32);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 1, &rust_biguint!(100), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, LOCKED_TOKEN_ID, 1, &rust_biguint!(100), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+
+            // 100 gov + 100 locked at 0.5x
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 150), sc.proposals(proposal_id).get().votes_for);
+            assert!(sc.proposal_token_nft_votes(proposal_id, &managed_token_id!(LOCKED_TOKEN_ID)).contains(&1));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, LOCKED_TOKEN_ID, 1, &rust_biguint!(100), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error("already voted with token");
+}
+
+#[test]
+fn it_fails_to_vote_with_unregistered_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup.blockchain.set_esdt_balance(&voter_address, LP_TOKEN_ID, &rust_biguint!(10_000));
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, LP_TOKEN_ID, 0, &rust_biguint!(10_000), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error("invalid payment token");
+}
+
+#[test]
+fn it_does_not_apply_restricted_gov_token_nonces_to_other_vote_tokens() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let voter_address = setup.user_address.clone();
+    let nft_token_id: &[u8] = b"NFT-abcdef";
+    let mut proposal_id = 0;
+
+    setup.configure_gov_token(true);
+
+    setup.blockchain.set_nft_balance(&voter_address, nft_token_id, 2, &rust_biguint!(1), &0);
+    setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), &0);
+
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            sc.set_vote_token_endpoint(managed_token_id!(ENTITY_GOV_TOKEN_ID), 100, 0, true);
+            sc.set_vote_token_endpoint(managed_token_id!(nft_token_id), 100, 0, true);
+            sc.set_restricted_vote_nonces_endpoint(MultiValueEncoded::from(ManagedVec::from(vec![0u64, 1u64])));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&setup.owner_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 0, &rust_biguint!(MIN_PROPOSE_WEIGHT), |sc| {
+            proposal_id = sc.propose_endpoint(
+                managed_buffer!(b"id"),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                POLL_DEFAULT_ID,
+                MultiValueManagedVec::new(),
+            );
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, 2, &rust_biguint!(1), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error("vote token nonce is restricted");
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, nft_token_id, 2, &rust_biguint!(1), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 1), sc.proposals(proposal_id).get().votes_for);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,137 @@
+use entity::config::*;
+use entity::governance::errors::*;
+use entity::governance::*;
+use multiversx_sc::codec::multi_types::*;
+use multiversx_sc::types::*;
+use multiversx_sc_scenario::*;
+use setup::*;
+
+mod setup;
+
+const NONCE_LEGENDARY: u64 = 1;
+const NONCE_COMMON: u64 = 2;
+const NONCE_UNLISTED: u64 = 3;
+
+fn configure_nonce_weights(setup: &mut EntitySetup<impl Fn() -> entity::ContractObj<DebugApi> + Copy>, default_weight: u64) {
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let mut weights = MultiValueEncoded::new();
+            weights.push((NONCE_LEGENDARY, managed_biguint!(10)).into());
+            weights.push((NONCE_COMMON, managed_biguint!(1)).into());
+            sc.set_vote_nonce_weights_endpoint(weights);
+            sc.change_vote_nonce_weight_default_endpoint(managed_biguint!(default_weight));
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .set_nft_balance(&setup.owner_address, ENTITY_GOV_TOKEN_ID, NONCE_COMMON, &rust_biguint!(MIN_PROPOSE_WEIGHT), &0u32);
+}
+
+#[test]
+fn it_weighs_votes_by_nonce() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(false);
+    configure_nonce_weights(&mut setup, 0);
+
+    let proposal_id = setup.propose_with_nonce(&owner_address, NONCE_COMMON, MIN_PROPOSE_WEIGHT, b"id");
+
+    setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, NONCE_LEGENDARY, &rust_biguint!(1), &0u32);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, NONCE_LEGENDARY, &rust_biguint!(1), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 10), sc.proposals(proposal_id).get().votes_for);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_weighs_unlisted_nonces_with_the_default_weight() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(false);
+    configure_nonce_weights(&mut setup, 2);
+
+    let proposal_id = setup.propose_with_nonce(&owner_address, NONCE_COMMON, MIN_PROPOSE_WEIGHT, b"id");
+
+    setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, NONCE_UNLISTED, &rust_biguint!(3), &0u32);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, NONCE_UNLISTED, &rust_biguint!(3), |sc| {
+            sc.vote_against_endpoint(proposal_id, OptionalValue::None);
+
+            assert_eq!(managed_biguint!(6), sc.proposals(proposal_id).get().votes_against);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn it_fails_to_vote_with_excluded_nonces() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(false);
+    configure_nonce_weights(&mut setup, 0);
+
+    let proposal_id = setup.propose_with_nonce(&owner_address, NONCE_COMMON, MIN_PROPOSE_WEIGHT, b"id");
+
+    setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, NONCE_UNLISTED, &rust_biguint!(1), &0u32);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, NONCE_UNLISTED, &rust_biguint!(1), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error("vote token nonce is excluded");
+}
+
+#[test]
+fn it_fails_to_vote_twice_with_the_same_weighted_nonce() {
+    let mut setup = EntitySetup::new(entity::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let voter_address = setup.user_address.clone();
+
+    setup.configure_gov_token(false);
+    configure_nonce_weights(&mut setup, 0);
+
+    let proposal_id = setup.propose_with_nonce(&owner_address, NONCE_COMMON, MIN_PROPOSE_WEIGHT, b"id");
+
+    setup.blockchain.set_nft_balance(&voter_address, ENTITY_GOV_TOKEN_ID, NONCE_LEGENDARY, &rust_biguint!(1), &0u32);
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, NONCE_LEGENDARY, &rust_biguint!(1), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_ok();
+
+    // changing the weight afterwards does not affect the votes already cast
+    setup
+        .blockchain
+        .execute_tx(setup.contract.address_ref(), &setup.contract, &rust_biguint!(0), |sc| {
+            let mut weights = MultiValueEncoded::new();
+            weights.push((NONCE_LEGENDARY, managed_biguint!(50)).into());
+            sc.set_vote_nonce_weights_endpoint(weights);
+
+            assert_eq!(managed_biguint!(MIN_PROPOSE_WEIGHT + 10), sc.proposals(proposal_id).get().votes_for);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain
+        .execute_esdt_transfer(&voter_address, &setup.contract, ENTITY_GOV_TOKEN_ID, NONCE_LEGENDARY, &rust_biguint!(1), |sc| {
+            sc.vote_for_endpoint(proposal_id, OptionalValue::None);
+        })
+        .assert_user_error(std::str::from_utf8(ALREADY_VOTED_WITH_TOKEN).unwrap());
+}
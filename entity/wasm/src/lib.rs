@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           69
+// Endpoints:                          160
 // Async Callback:                       1
-// Total number of exported functions:  71
+// Total number of exported functions: 162
 
 #![no_std]
 
@@ -24,23 +24,50 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         changeVoteTokenLock => change_vote_token_lock_endpoint
+        setSignedVoteChainId => set_signed_vote_chain_id_endpoint
         registerDns => register_dns
         getVersion => version_view
+        getCurrentQuorum => get_quorum
+        getCirculatingSupply => get_circulating_supply
+        getPassThreshold => get_pass_threshold
         getTrustedHostAddress => trusted_host_address
         getGovTokenId => gov_token_id
         getGuardedVoteTokens => guarded_vote_tokens
+        getLockedVoteTokenLayout => locked_vote_token_layout
         isLockingVoteTokens => lock_vote_tokens
         getProposalIdCounter => next_proposal_id
+        getProposalsByProposer => proposer_proposals
         getProposalNftVotes => proposal_nft_votes
         getWithdrawableVoters => withdrawable_voters
         getWithdrawableProposalIds => withdrawable_proposal_ids
         getWithdrawableVotes => withdrawable_votes
+        getVoteReceipt => vote_receipts
         getProposalAddressVotes => votes
         getQuorum => quorum
+        getQuorumPercent => quorum_percent
+        getGovTokenSupply => gov_token_supply
+        getDynamicQuorum => dynamic_quorum
+        getEmergencyConfig => emergency_config
+        getEmergencyPermissions => emergency_permissions
+        getQuorumMode => quorum_mode
+        isQuadraticVoting => quadratic_voting
+        getQuadraticVoteTokens => quadratic_vote_tokens
+        getVoteMode => vote_mode
         getMinVoteWeight => min_vote_weight
         getMinProposeWeight => min_propose_weight
         getVotingPeriodMinutes => voting_period_in_minutes
+        getVotingDelayMinutes => voting_delay_in_minutes
+        getExecutionDelayMinutes => execution_delay_in_minutes
+        getGracePeriodMinutes => grace_period_in_minutes
+        getMaxActiveProposals => max_active_proposals
+        getProposalCooldownMinutes => proposal_cooldown_in_minutes
+        getProposalRateLimitExemptRoles => proposal_rate_limit_exempt_roles
+        getLateVoteWindowMinutes => late_vote_window_in_minutes
+        getLateVoteExtensionMinutes => late_vote_extension_in_minutes
+        getMaxLateVoteExtensions => max_late_vote_extensions
+        getProposalExtensions => proposal_extensions
         getRestrictedVoteNonces => restricted_vote_nonces
+        getVoteNonceWeightDefault => vote_nonce_weight_default
         lockContractStage => lock_contract_stage_endpoint
         unlockContractStage => unlock_contract_stage_endpoint
         stageContract => stage_contract_endpoint
@@ -51,46 +78,109 @@ multiversx_sc_wasm_adapter::endpoints! {
         removeRole => remove_role_endpoint
         assignRole => assign_role_endpoint
         unassignRole => unassign_role_endpoint
+        setGuardianRole => set_guardian_role_endpoint
+        removeGuardianRole => remove_guardian_role_endpoint
         createPermission => create_permission_endpoint
         createPolicyWeighted => create_policy_weighted_endpoint
+        changePolicyExecutionDelay => change_policy_execution_delay_endpoint
         createPolicyForOne => create_policy_one_endpoint
         createPolicyForAll => create_policy_all_endpoint
         createPolicyQuorum => create_policy_quorum_endpoint
+        createPolicyOptimistic => create_policy_optimistic_endpoint
         getUserRoles => get_user_roles_view
         getPermissions => get_permissions_view
         getPolicies => get_policies_view
         getRoles => roles
         getRoleMemberAmount => roles_member_amount
+        getGuardianRole => guardian_role
         hasUserPlugVoted => has_user_plug_voted_view
         getPlug => get_plug_view
         initGovToken => init_gov_token_endpoint
         changeGovToken => change_gov_token_endpoint
         removeGovToken => remove_gov_token_endpoint
         changeQuorum => change_quorum_endpoint
+        changeQuorumPercent => change_quorum_percent_endpoint
+        setDynamicQuorum => set_dynamic_quorum_endpoint
+        removeDynamicQuorum => remove_dynamic_quorum_endpoint
+        changePassThreshold => change_pass_threshold_endpoint
+        changeExecutionDelay => change_execution_delay_endpoint
+        changeGracePeriod => change_grace_period_endpoint
+        changeQuorumMode => change_quorum_mode_endpoint
+        changeVoteMode => change_vote_mode_endpoint
+        changeQuadraticVoting => change_quadratic_voting_endpoint
+        setVoteToken => set_vote_token_endpoint
+        removeVoteToken => remove_vote_token_endpoint
+        setLockedVoteTokenLayout => set_locked_vote_token_layout_endpoint
+        removeLockedVoteTokenLayout => remove_locked_vote_token_layout_endpoint
         changeMinVoteWeight => change_min_vote_weight_endpoint
         changeMinProposeWeight => change_min_propose_weight_endpoint
         changeVotingPeriodMinutes => change_voting_period_in_minutes_endpoint
+        changeVotingDelayMinutes => change_voting_delay_in_minutes_endpoint
+        changeMaxActiveProposals => change_max_active_proposals_endpoint
+        changeProposalCooldownMinutes => change_proposal_cooldown_in_minutes_endpoint
+        setProposalRateLimitExemptRoles => set_proposal_rate_limit_exempt_roles_endpoint
+        changeLateVoteExtension => change_late_vote_extension_endpoint
+        setEmergencyConfig => set_emergency_config_endpoint
+        removeEmergencyConfig => remove_emergency_config_endpoint
+        setEmergencyPermissions => set_emergency_permissions_endpoint
         setRestrictedVoteNonces => set_restricted_vote_nonces_endpoint
+        setVoteNonceWeights => set_vote_nonce_weights_endpoint
+        changeVoteNonceWeightDefault => change_vote_nonce_weight_default_endpoint
+        setProposalDeposit => set_proposal_deposit_endpoint
+        removeProposalDeposit => remove_proposal_deposit_endpoint
         setPlug => set_plug_endpoint
         propose => propose_endpoint
+        proposeEmergency => propose_emergency_endpoint
+        proposePoll => propose_poll_endpoint
         voteFor => vote_for_endpoint
         voteAgainst => vote_against_endpoint
+        voteAbstain => vote_abstain_endpoint
+        votePoll => vote_poll_endpoint
+        submitSignedVotes => submit_signed_votes_endpoint
+        changeVote => change_vote_endpoint
+        revokeVote => revoke_vote_endpoint
         sign => sign_endpoint
         execute => execute_endpoint
         directExecute => direct_execute_endpoint
         cancelProposal => cancel_proposal_endpoint
+        veto => veto_endpoint
+        flagSpam => flag_spam_endpoint
+        delegate => delegate_endpoint
+        undelegate => undelegate_endpoint
+        lockEscrow => lock_escrow_endpoint
+        extendEscrow => extend_escrow_endpoint
+        increaseEscrow => increase_escrow_endpoint
+        withdrawEscrow => withdraw_escrow_endpoint
         withdraw => withdraw_endpoint
         withdrawAll => withdraw_all_endpoint
         issueGovToken => issue_gov_token_endpoint
         setGovTokenLocalRoles => set_gov_token_local_roles_endpoint
         mint => mint_endpoint
         burn => burn_endpoint
+        getVoteTokens => get_vote_tokens_view
+        getVoteNonceWeights => get_vote_nonce_weights_view
+        getVoteNonceWeight => get_vote_nonce_weight_view
+        getEffectiveQuorum => get_effective_quorum_view
         getProposal => get_proposal_view
         getProposalStatus => get_proposal_status_view
+        getProposalExecutionDelayRemaining => get_proposal_execution_delay_remaining_view
         getProposalVotes => get_proposal_votes_view
         getProposalSigners => get_proposal_signers_view
         getProposalSignatureRoleCounts => get_proposal_signature_role_counts_view
         getProposalPollResults => get_proposal_poll_results_view
+        getPollWinner => get_poll_winner_view
+        getDelegators => get_delegators_view
+        getDelegatedVoteWeight => get_delegated_vote_weight_view
+        getDelegate => delegate
+        getDelegatedTokens => delegated_tokens
+        getProposalDeposit => proposal_deposit
+        getProposalDepositOf => proposal_deposits
+        isProposalSpam => proposal_spam_flags
+        getEscrowVotingPower => get_escrow_voting_power_view
+        getEscrow => escrow_locks
+        getPoll => polls
+        getSignedVoteChainId => signed_vote_chain_id
+        getSignedVoteNonce => signed_vote_nonces
     )
 }
 